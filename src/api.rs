@@ -1,6 +1,6 @@
 use serde_json;
 // use serde_json::{Value, json};
-use serde_derive::{Deserialize, Serialize};
+use serde_derive::Deserialize;
 use serde::de::Deserialize;
 
 use reqwest::header::{
@@ -14,39 +14,61 @@ use std::borrow::Cow;
 use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Debug;
-use std::hash::Hash;
 use std::io::Read;
 use std::string::{String, ToString};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use super::model::album::{Album, AlbumTrack, ArtistAlbums, TopAlbumRes};
-use super::model::artist::{Artist, TopArtistRes};
+use super::model::artist::{Artist, ArtistDetailRes, TopArtistRes};
+use super::model::comment::{Comment, CommentRes};
 use super::model::dj::{DjProgram, DjRadio, ProgramDetailRes, ProgramsRes, SubDjRadioRes};
 use super::model::lyric::{Lyric, LyricRes};
+use super::model::personalized::{
+    PersonalizedNewSongRes, PersonalizedPlaylist, PersonalizedPlaylistRes, PersonalizedProgram,
+    PersonalizedProgramRes,
+};
 use super::model::playlist::{
-    PersonalFmRes, Playlist, PlaylistDetail, PlaylistDetailRes, PlaylistRes, TopPlaylistRes, Track, UidPlaylistRes
+    IntelligenceRes, LikedIdsRes, PersonalFmRes, PlayRecord, PlayRecordRes, Playlist, PlaylistCatalogueRes, PlaylistCategory, PlaylistCreateRes, RecommendSongsRes, SimilarSongsRes, Toplist, ToplistRes, PlaylistDetail, PlaylistDetailRes, PlaylistRes, TopPlaylistRes, Track, UidPlaylistRes
 };
 use super::model::search::{
     SearchAlbumResult, SearchAlbums, SearchArtistResult, SearchArtists, SearchDjRadios,
-    SearchDjradioResult, SearchPlaylistResult, SearchPlaylists, SearchTrackResult, SearchTracks,
+    SearchDjradioResult, SearchHotRes, SearchLyricResult, SearchLyrics, SearchPlaylistResult,
+    SearchPlaylists, SearchSuggest, SearchSuggestRes, SearchTrackResult, SearchTracks,
+    SearchUserResult, SearchUsers,
 };
 use super::model::song::{Song, Songs};
-use super::model::user::{Login, Profile, User};
+use super::model::user::{CaptchaRes, Login, Profile, QrCheckRes, QrKeyRes, User};
 
+use super::util::cookie::{self, CookieJar, StoredCookie};
+use super::util::paths;
+use super::util::settings::api_proxy;
 use super::util::Encrypt;
 use chrono::prelude::*;
 use failure::err_msg;
 use openssl::hash::{hash, MessageDigest};
-use std::fs;
 
 lazy_static! {
     /// HTTP Client
-    pub static ref CLIENT: Client = reqwest::blocking::Client::builder()
-        .gzip(true)
-        // .cookie_store(true)
-        .timeout(Duration::from_secs(10))
-        .build()
-        .unwrap();
+    pub static ref CLIENT: Client = {
+        let builder = reqwest::blocking::Client::builder()
+            .gzip(true)
+            // .cookie_store(true)
+            .timeout(Duration::from_secs(10));
+        // proxy can set in config or env
+        let builder = match api_proxy() {
+            Some(proxy) => match reqwest::Proxy::all(&proxy) {
+                Ok(proxy) => builder.proxy(proxy),
+                Err(e) => {
+                    error!("invalid proxy {}: {}", proxy, e);
+                    builder
+                }
+            },
+            None => builder,
+        };
+        builder.build().unwrap()
+    };
 }
 
 #[derive(Debug)]
@@ -60,10 +82,25 @@ impl failure::Fail for ApiError {}
 
 impl fmt::Display for ApiError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Netease Cloud Music API reported an error")
+        match self {
+            ApiError::Unauthorized => write!(f, "Login expired, please login again"),
+            _ => write!(f, "Netease Cloud Music API reported an error"),
+        }
     }
 }
 
+// api code in response body
+#[derive(Deserialize)]
+struct ApiCode {
+    code: Option<i32>,
+}
+
+// host of api, cookies are sent only if match it
+const COOKIE_HOST: &str = "music.163.com";
+
+// response code when not login
+const CODE_NEED_LOGIN: i32 = 301;
+
 impl From<&reqwest::blocking::Response> for ApiError {
     fn from(response: &reqwest::blocking::Response) -> Self {
         match response.status() {
@@ -80,35 +117,39 @@ impl From<&reqwest::blocking::Response> for ApiError {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
 pub struct CloudMusic {
-    pub prefix: String,
-    pub cookie_path: String,
+    // shared by clones, so all api calls see new cookies
+    cookie_jar: Arc<Mutex<CookieJar>>,
+    // set when any api call return not login code
+    session_expired: Arc<AtomicBool>,
 }
 
 impl CloudMusic {
     pub fn default() -> CloudMusic {
+        CloudMusic::for_profile(paths::DEFAULT_PROFILE)
+    }
+
+    // api client using cookie of given profile
+    pub fn for_profile(profile: &str) -> CloudMusic {
         CloudMusic {
-            prefix: "https://music.163.com".to_owned(),
-            cookie_path: "/tmp/ncmt_cookie".to_owned(),
+            // cookies only kept in memory when no data dir
+            cookie_jar: Arc::new(Mutex::new(
+                paths::cookie_file(profile)
+                    .map(CookieJar::load)
+                    .unwrap_or_default(),
+            )),
+            session_expired: Arc::new(AtomicBool::new(false)),
         }
     }
 
-    ///send get request
-    fn get(
-        &self,
-        url: &str,
-        params: &mut HashMap<String, String>,
-    ) -> Result<String, failure::Error> {
-        if !params.is_empty() {
-            let param: String = convert_map_to_string(params);
-            let mut url_with_params = url.to_owned();
-            url_with_params.push('?');
-            url_with_params.push_str(&param);
-            self.internal_call(Method::GET, &url_with_params, None)
-        } else {
-            self.internal_call(Method::GET, url, None)
-        }
+    // check if login expired since last login
+    pub fn session_expired(&self) -> bool {
+        self.session_expired.load(Ordering::Relaxed)
+    }
+
+    pub fn reset_session_expired(&self) {
+        self.session_expired.store(false, Ordering::Relaxed)
     }
 
     // send post request
@@ -122,8 +163,7 @@ impl CloudMusic {
         params.insert("csrf_token".to_owned(), csrf_token);
         let params = Encrypt::encrypt_login(params);
         // let param = json!(params);
-        let a = self.internal_call(Method::POST, &url, Some(params));
-        Ok(a.unwrap())
+        self.internal_call(Method::POST, url, Some(params))
     }
 
     fn internal_call(
@@ -153,26 +193,29 @@ impl CloudMusic {
         headers.insert(HOST, "music.163.com".parse().unwrap());
         headers.insert(ACCEPT_ENCODING, "gzip,deflate".parse().unwrap());
 
-        match method {
+        // device cookies for weapi, saved cookies with same name go first
+        let extra = match method {
             Method::POST => {
-                let name = "os";
-                let value = "pc";
                 let local: DateTime<Local> = Local::now();
                 let times = local.timestamp();
                 let hextoken =
                     hex::encode(hash(MessageDigest::md5(), &times.to_string().as_bytes()).unwrap());
-
-                // read local save cookie
-                let data = fs::read_to_string(&self.cookie_path).unwrap_or(String::new());
-                let make_cookie = format!("version=0;{}={};JSESSIONID-WYYY=%2FKSy%2B4xG6fYVld42G9E%2BxAj9OyjC0BYXENKxOIRH%5CR72cpy9aBjkohZ24BNkpjnBxlB6lzAG4D%5C%2FMNUZ7VUeRUeVPJKYu%2BKBnZJjEmqgpOx%2BU6VYmypKB%5CXb%2F3W7%2BDjOElCb8KlhDS2cRkxkTb9PBDXro41Oq7aBB6M6OStEK8E%2Flyc8%3A{}; _iuqxldmzr_=32; _ntes_nnid={},{}; _ntes_nuid={}; {}", name, value, times, hextoken, hextoken, times + 50, data);
-                headers.insert(COOKIE, make_cookie.parse().unwrap());
-            }
-            Method::GET => {
-                let data = fs::read_to_string(&self.cookie_path).unwrap_or(String::new());
-                let make_cookie = format!("{}", data);
-                headers.insert(COOKIE, make_cookie.parse().unwrap());
+                vec![
+                    ("os", "pc".to_owned()),
+                    ("_iuqxldmzr_", "32".to_owned()),
+                    ("_ntes_nnid", format!("{},{}", hextoken, times + 50)),
+                    ("_ntes_nuid", hextoken),
+                ]
             }
-            _ => {}
+            _ => vec![],
+        };
+        let cookie = self
+            .cookie_jar
+            .lock()
+            .unwrap()
+            .header(COOKIE_HOST, &extra, cookie::now());
+        if let Ok(cookie) = cookie.parse() {
+            headers.insert(COOKIE, cookie);
         }
         let mut response = {
             let builder = CLIENT.request(method, &url.into_owned()).headers(headers);
@@ -194,6 +237,14 @@ impl CloudMusic {
         response
             .read_to_string(&mut buf)
             .expect("failed to read response");
+        // login expired, need login again
+        if let Ok(ApiCode {
+            code: Some(CODE_NEED_LOGIN),
+        }) = serde_json::from_str::<ApiCode>(&buf)
+        {
+            self.session_expired.store(true, Ordering::Relaxed);
+            return Err(failure::Error::from(ApiError::Unauthorized));
+        }
         if response.status().is_success() {
             Ok(buf)
         } else if response.status() == 403 {
@@ -203,16 +254,22 @@ impl CloudMusic {
         }
     }
 
+    // merge cookies of response into jar and save it
     fn store_cookies(&self, res: &reqwest::blocking::Response) {
-        let cookies: Vec<String> = res
+        let now = cookie::now();
+        let cookies = res
             .cookies()
-            .into_iter()
-            .map(|s| format!("{}={}", s.name().to_string(), s.value().to_string()))
-            .collect();
-        let mut c: String = cookies.into_iter().map(|s| format!("{}; ", s)).collect();
-        c.pop();
-        if c.len() > 0 {
-            fs::write(&self.cookie_path, &c).expect("Unable to write file");
+            .map(|c| StoredCookie::from_response(&c, now))
+            .collect::<Vec<StoredCookie>>();
+        if cookies.is_empty() {
+            return;
+        }
+        let mut jar = self.cookie_jar.lock().unwrap();
+        for c in cookies {
+            jar.insert(c, now);
+        }
+        if let Err(e) = jar.save() {
+            error!("save cookie failed: {}", e);
         }
     }
 
@@ -240,12 +297,7 @@ impl CloudMusic {
         params.insert("rememberLogin".to_owned(), "true".to_owned());
 
         let result = self.post(&url, &mut params)?;
-        info!("{}", result);
-        let login = self.convert_result::<Login>(&result)?;
-        match login.profile {
-            Some(profile) => Ok(profile),
-            None => Err(err_msg("login failed")),
-        }
+        self.login_result(&result)
     }
 
     // cellphone login
@@ -258,48 +310,118 @@ impl CloudMusic {
         params.insert("rememberLogin".to_owned(), "true".to_owned());
 
         let result = self.post(&url, &mut params)?;
-        let login = self.convert_result::<Login>(&result).unwrap();
+        self.login_result(&result)
+    }
+
+    // cellphone login with sms verification code
+    pub fn captcha_login(&self, phone: &str, captcha: &str) -> Result<Profile, failure::Error> {
+        let url = "/weapi/login/cellphone";
+        let mut params = HashMap::new();
+        params.insert("phone".to_owned(), phone.to_string());
+        params.insert("captcha".to_owned(), captcha.to_string());
+        params.insert("countrycode".to_owned(), "86".to_owned());
+        params.insert("rememberLogin".to_owned(), "true".to_owned());
+
+        let result = self.post(url, &mut params)?;
+        self.login_result(&result)
+    }
+
+    // send sms verification code to cellphone
+    pub fn sms_captcha_sent(&self, phone: &str) -> Result<(), failure::Error> {
+        let url = "/weapi/sms/captcha/sent";
+        let mut params = HashMap::new();
+        params.insert("cellphone".to_owned(), phone.to_string());
+        params.insert("ctcode".to_owned(), "86".to_owned());
+
+        let result = self.post(url, &mut params)?;
+        let res = self.convert_result::<CaptchaRes>(&result)?;
+        match res.code {
+            200 => Ok(()),
+            code => Err(login_error(code, res.message)),
+        }
+    }
+
+    // get profile from login response, or error with code
+    fn login_result(&self, result: &str) -> Result<Profile, failure::Error> {
+        info!("{}", result);
+        let login = self.convert_result::<Login>(result)?;
         match login.profile {
             Some(profile) => Ok(profile),
-            None => Err(err_msg("login failed")),
+            None => Err(login_error(
+                login.code.unwrap_or(0),
+                login.msg.or(login.message),
+            )),
         }
     }
 
+    // get unikey for qrcode login
+    pub fn login_qr_key(&self) -> Result<String, failure::Error> {
+        let url = "/weapi/login/qrcode/unikey";
+        let mut params = HashMap::new();
+        params.insert("type".to_owned(), 1.to_string());
+
+        let result = self.post(url, &mut params)?;
+        match self.convert_result::<QrKeyRes>(&result) {
+            Ok(QrKeyRes {
+                unikey: Some(unikey),
+                ..
+            }) => Ok(unikey),
+            _ => Err(err_msg("get qrcode login key failed")),
+        }
+    }
+
+    // check qrcode login status, return code
+    // 800 expired, 801 waiting for scan, 802 waiting for confirm, 803 success
+    // cookie is stored when login success
+    pub fn login_qr_check(&self, unikey: &str) -> Result<i32, failure::Error> {
+        let url = "/weapi/login/qrcode/client/login";
+        let mut params = HashMap::new();
+        params.insert("key".to_owned(), unikey.to_string());
+        params.insert("type".to_owned(), 1.to_string());
+
+        let result = self.post(url, &mut params)?;
+        let res = self.convert_result::<QrCheckRes>(&result)?;
+        Ok(res.code)
+    }
+
+    // get login account, None if not login
     pub fn login_status(&self) -> Result<Option<Profile>, failure::Error> {
-        let url = format!("/");
-        match self.get(&url, &mut HashMap::new()) {
-            Ok(r) => {
-                let re = regex::Regex::new(r#"userId:(?P<id>\d+),nickname:"(?P<nickname>\w+)""#)
-                    .unwrap();
-                if let Some(cap) = re.captures(&r) {
-                    let uid = cap.name("id").unwrap().as_str().parse::<i32>().unwrap_or(0);
-                    let nickname = cap.name("nickname").unwrap().as_str().to_owned();
-                    Ok(Some(Profile {
-                        userId: Some(uid),
-                        nickname: Some(nickname),
-                        gender: None,
-                        follows: None,
-                        followeds: None,
-                    }))
-                } else {
-                    Ok(None)
-                }
-            }
-            Err(e) => {
-                panic!("api error {}", e);
-            }
+        let url = "/weapi/w/nuser/account/get";
+        let mut params = HashMap::new();
+
+        let result = match self.post(url, &mut params) {
+            Ok(result) => result,
+            Err(e) => match e.downcast_ref::<ApiError>() {
+                Some(ApiError::Unauthorized) => return Ok(None),
+                _ => return Err(e),
+            },
+        };
+        let res = self.convert_result::<Login>(&result)?;
+        match (res.code, res.account) {
+            (Some(200), Some(_)) => Ok(res.profile),
+            _ => Ok(None),
         }
     }
 
-    #[allow(dead_code)]
+    // refresh login token to keep session alive
+    pub fn refresh_token(&self) -> Result<(), failure::Error> {
+        let url = "/weapi/login/token/refresh";
+        let mut params = HashMap::new();
+
+        let result = self.post(url, &mut params)?;
+        self.check_code(&result, "refresh login token failed")
+    }
+
+    // user detail with level and listen count
     pub fn user(&self, user_id: &str) -> Result<User, failure::Error> {
-        let url = format!("/user/detail");
-        // url.push_str(&trid);
+        let url = format!("/weapi/v1/user/detail/{}", user_id);
         let mut params = HashMap::new();
-        params.insert("uid".to_owned(), user_id.to_string());
 
-        let result = self.get(&url, &mut params)?;
-        self.convert_result::<User>(&result)
+        let result = self.post(&url, &mut params)?;
+        match self.convert_result::<User>(&result) {
+            Ok(user) if user.profile.is_some() => Ok(user),
+            _ => Err(err_msg("get user detail failed")),
+        }
     }
 
     // get song url
@@ -327,6 +449,24 @@ impl CloudMusic {
         }
     }
 
+    // daily recommend songs, need login
+    pub fn recommend_songs(&self) -> Result<Vec<Track>, failure::Error> {
+        let url = "/weapi/v1/discovery/recommend/songs";
+        let mut params = HashMap::new();
+        params.insert("limit".to_owned(), 30.to_string());
+        params.insert("offset".to_owned(), 0.to_string());
+        params.insert("total".to_owned(), true.to_string());
+
+        let result = self.post(url, &mut params)?;
+        match self.convert_result::<RecommendSongsRes>(&result) {
+            Ok(RecommendSongsRes {
+                recommend: Some(tracks),
+                ..
+            }) => Ok(tracks),
+            _ => Err(err_msg("get daily recommend songs failed")),
+        }
+    }
+
     // user playlist api
     pub fn user_playlists(&self, uid: &str) -> Result<Vec<Playlist>, failure::Error> {
         let url = format!("/weapi/user/playlist");
@@ -372,6 +512,57 @@ impl CloudMusic {
         Ok(res.hotAlbums.unwrap().clone())
     }
 
+    // artist info and top 50 songs
+    pub fn artist_detail(&self, artist_id: &str) -> Result<(Artist, Vec<Track>), failure::Error> {
+        let url = format!("/weapi/v1/artist/{}", artist_id);
+        let mut params = HashMap::new();
+
+        let result = self.post(&url, &mut params)?;
+        match self.convert_result::<ArtistDetailRes>(&result) {
+            Ok(ArtistDetailRes {
+                artist: Some(artist),
+                hotSongs: songs,
+                ..
+            }) => Ok((
+                artist,
+                songs
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(Track::from)
+                    .collect(),
+            )),
+            _ => Err(err_msg("get artist detail failed")),
+        }
+    }
+
+    // similar artists, need login
+    pub fn similar_artists(&self, artist_id: &str) -> Result<Vec<Artist>, failure::Error> {
+        let url = "/weapi/discovery/simiArtist";
+        let mut params = HashMap::new();
+        params.insert("artistid".to_owned(), artist_id.to_string());
+
+        let result = self.post(url, &mut params)?;
+        match self.convert_result::<TopArtistRes>(&result) {
+            Ok(res) => Ok(res.artists),
+            Err(_) => Err(err_msg("get similar artists failed")),
+        }
+    }
+
+    // follow or unfollow artist
+    pub fn follow_artist(&self, artist_id: &str, follow: bool) -> Result<(), failure::Error> {
+        let url = if follow {
+            "/weapi/artist/sub"
+        } else {
+            "/weapi/artist/unsub"
+        };
+        let mut params = HashMap::new();
+        params.insert("artistId".to_owned(), artist_id.to_string());
+        params.insert("artistIds".to_owned(), format!("[{}]", artist_id));
+
+        let result = self.post(url, &mut params)?;
+        self.check_code(&result, "follow artist failed")
+    }
+
     // album track
     pub fn album_track(&self, album_id: &str) -> Result<AlbumTrack, failure::Error> {
         let url = format!("/weapi/v1/album/{}", album_id);
@@ -387,12 +578,11 @@ impl CloudMusic {
     }
 
     // other user playlist
-    #[allow(unused)]
     pub fn uid_playlists(&self, user_id: &str) -> Result<Vec<Playlist>, failure::Error> {
         let url = format!("/weapi/user/playlist");
         let mut params = HashMap::new();
         params.insert("uid".to_owned(), user_id.to_string());
-        params.insert("limit".to_owned(), 30.to_string());
+        params.insert("limit".to_owned(), 1000.to_string());
         params.insert("offset".to_owned(), 0.to_string());
 
         let result = self.post(&url, &mut params)?;
@@ -579,6 +769,66 @@ impl CloudMusic {
         Ok(res.result)
     }
 
+    // search for lyric, tracks with matched lines
+    pub fn search_lyric(
+        &self,
+        keyword: &str,
+        limit: i32,
+        offset: i32,
+    ) -> Result<SearchLyrics, failure::Error> {
+        let result = self.search(keyword, "1006", limit, offset)?;
+        let res = self.convert_result::<SearchLyricResult>(&result)?;
+        Ok(res.result)
+    }
+
+    // search for user
+    pub fn search_user(
+        &self,
+        keyword: &str,
+        limit: i32,
+        offset: i32,
+    ) -> Result<SearchUsers, failure::Error> {
+        let result = self.search(keyword, "1002", limit, offset)?;
+        let res = self.convert_result::<SearchUserResult>(&result)?;
+        Ok(res.result)
+    }
+
+    // suggestions while typing keyword
+    pub fn search_suggest(&self, keyword: &str) -> Result<SearchSuggest, failure::Error> {
+        let url = "/weapi/search/suggest/web";
+        let mut params = HashMap::new();
+        params.insert("s".to_owned(), keyword.to_string());
+
+        let result = self.post(url, &mut params)?;
+        match self.convert_result::<SearchSuggestRes>(&result) {
+            Ok(SearchSuggestRes {
+                result: Some(suggest),
+                ..
+            }) => Ok(suggest),
+            _ => Err(err_msg("get search suggestions failed")),
+        }
+    }
+
+    // hot search keywords
+    pub fn search_hot(&self) -> Result<Vec<String>, failure::Error> {
+        let url = "/weapi/search/hot";
+        let mut params = HashMap::new();
+        params.insert("type".to_owned(), "1111".to_string());
+
+        let result = self.post(url, &mut params)?;
+        match self.convert_result::<SearchHotRes>(&result) {
+            Ok(SearchHotRes {
+                result: Some(hots), ..
+            }) => Ok(hots
+                .hots
+                .unwrap_or_default()
+                .into_iter()
+                .map(|hot| hot.first)
+                .collect()),
+            _ => Err(err_msg("get hot searches failed")),
+        }
+    }
+
     // get user personal fm
     pub fn personal_fm(&self) -> Result<Vec<Track>, failure::Error> {
         let url = format!("/weapi/v1/radio/get");
@@ -590,12 +840,145 @@ impl CloudMusic {
         Ok(res.data)
     }
 
-    // top songlist
-    pub fn top_playlists(&self, limit: i32, offset: i32) -> Result<Vec<Playlist>, failure::Error> {
+    // songs similar to the track
+    pub fn similar_songs(&self, id: i64) -> Result<Vec<Track>, failure::Error> {
+        let url = "/weapi/v1/discovery/simiSong";
+        let mut params = HashMap::new();
+        params.insert("songid".to_owned(), id.to_string());
+        params.insert("limit".to_owned(), 50.to_string());
+        params.insert("offset".to_owned(), 0.to_string());
+
+        let result = self.post(url, &mut params)?;
+        match self.convert_result::<SimilarSongsRes>(&result) {
+            Ok(SimilarSongsRes {
+                songs: Some(songs), ..
+            }) => Ok(songs),
+            _ => Err(err_msg("get similar songs failed")),
+        }
+    }
+
+    // intelligence mode of playlist, seeded by track and continues from start track
+    pub fn intelligence_list(
+        &self,
+        id: i64,
+        playlist_id: i64,
+        start_id: i64,
+    ) -> Result<Vec<Track>, failure::Error> {
+        let url = "/weapi/playmode/intelligence/list";
+        let mut params = HashMap::new();
+        params.insert("songId".to_owned(), id.to_string());
+        params.insert("type".to_owned(), "fromPlayOne".to_string());
+        params.insert("playlistId".to_owned(), playlist_id.to_string());
+        params.insert("startMusicId".to_owned(), start_id.to_string());
+        params.insert("count".to_owned(), 1.to_string());
+
+        let result = self.post(url, &mut params)?;
+        match self.convert_result::<IntelligenceRes>(&result) {
+            Ok(IntelligenceRes {
+                data: Some(data), ..
+            }) => Ok(data
+                .into_iter()
+                .filter_map(|t| t.songInfo)
+                .map(Track::from)
+                .collect()),
+            _ => Err(err_msg("get intelligence tracks failed")),
+        }
+    }
+
+    // recommend playlists for current user
+    pub fn personalized_playlists(&self, limit: i32) -> Result<Vec<PersonalizedPlaylist>, failure::Error> {
+        let url = "/weapi/personalized/playlist";
+        let mut params = HashMap::new();
+        params.insert("limit".to_owned(), limit.to_string());
+        params.insert("total".to_owned(), true.to_string());
+        params.insert("n".to_owned(), 1000.to_string());
+
+        let result = self.post(url, &mut params)?;
+        match self.convert_result::<PersonalizedPlaylistRes>(&result) {
+            Ok(PersonalizedPlaylistRes {
+                result: Some(playlists),
+                ..
+            }) => Ok(playlists),
+            _ => Err(err_msg("get recommend playlists failed")),
+        }
+    }
+
+    // recommend new songs
+    pub fn personalized_new_songs(&self) -> Result<Vec<Track>, failure::Error> {
+        let url = "/weapi/personalized/newsong";
+        let mut params = HashMap::new();
+        params.insert("type".to_owned(), "recommend".to_string());
+
+        let result = self.post(url, &mut params)?;
+        match self.convert_result::<PersonalizedNewSongRes>(&result) {
+            Ok(PersonalizedNewSongRes {
+                result: Some(songs),
+                ..
+            }) => Ok(songs.into_iter().map(|s| s.song).collect()),
+            _ => Err(err_msg("get recommend new songs failed")),
+        }
+    }
+
+    // recommend dj programs
+    pub fn personalized_programs(&self) -> Result<Vec<PersonalizedProgram>, failure::Error> {
+        let url = "/weapi/personalized/djprogram";
+        let mut params = HashMap::new();
+
+        let result = self.post(url, &mut params)?;
+        match self.convert_result::<PersonalizedProgramRes>(&result) {
+            Ok(PersonalizedProgramRes {
+                result: Some(programs),
+                ..
+            }) => Ok(programs),
+            _ => Err(err_msg("get recommend dj programs failed")),
+        }
+    }
+
+    // playlist categories and their group names
+    pub fn playlist_categories(
+        &self,
+    ) -> Result<(Vec<PlaylistCategory>, HashMap<String, String>), failure::Error> {
+        let url = "/weapi/playlist/catalogue";
+        let mut params = HashMap::new();
+
+        let result = self.post(url, &mut params)?;
+        match self.convert_result::<PlaylistCatalogueRes>(&result) {
+            Ok(PlaylistCatalogueRes {
+                sub: Some(categories),
+                categories: groups,
+                ..
+            }) => Ok((categories, groups.unwrap_or_default())),
+            _ => Err(err_msg("get playlist categories failed")),
+        }
+    }
+
+    // official charts
+    pub fn toplists(&self) -> Result<Vec<Toplist>, failure::Error> {
+        let url = "/weapi/toplist/detail";
+        let mut params = HashMap::new();
+
+        let result = self.post(url, &mut params)?;
+        match self.convert_result::<ToplistRes>(&result) {
+            Ok(ToplistRes {
+                list: Some(toplists),
+                ..
+            }) => Ok(toplists),
+            _ => Err(err_msg("get charts failed")),
+        }
+    }
+
+    // top songlist of category, order by hot or new
+    pub fn top_playlists(
+        &self,
+        category: &str,
+        hot: bool,
+        limit: i32,
+        offset: i32,
+    ) -> Result<Vec<Playlist>, failure::Error> {
         let url = format!("/weapi/playlist/list");
         let mut params = HashMap::new();
-        params.insert("cat".to_owned(), "全部".to_string());
-        params.insert("order".to_owned(), "hot".to_string());
+        params.insert("cat".to_owned(), category.to_string());
+        params.insert("order".to_owned(), if hot { "hot" } else { "new" }.to_string());
         params.insert("limit".to_owned(), limit.to_string());
         params.insert("offset".to_owned(), offset.to_string());
         params.insert("total".to_owned(), true.to_string());
@@ -618,11 +1001,36 @@ impl CloudMusic {
         Ok(res.artists)
     }
 
-    // top album
-    pub fn top_albums(&self, limit: i32, offset: i32) -> Result<Vec<Album>, failure::Error> {
+    // artist directory, -1 means all for area, type and initial
+    pub fn artist_list(
+        &self,
+        area: i32,
+        kind: i32,
+        initial: i32,
+        limit: i32,
+        offset: i32,
+    ) -> Result<Vec<Artist>, failure::Error> {
+        let url = "/weapi/v1/artist/list";
+        let mut params = HashMap::new();
+        params.insert("area".to_owned(), area.to_string());
+        params.insert("type".to_owned(), kind.to_string());
+        params.insert("initial".to_owned(), initial.to_string());
+        params.insert("limit".to_owned(), limit.to_string());
+        params.insert("offset".to_owned(), offset.to_string());
+        params.insert("total".to_owned(), true.to_string());
+
+        let result = self.post(url, &mut params)?;
+        match self.convert_result::<TopArtistRes>(&result) {
+            Ok(res) => Ok(res.artists),
+            Err(_) => Err(err_msg("get artist list failed")),
+        }
+    }
+
+    // new albums of area, ALL, ZH, EA, KR or JP
+    pub fn top_albums(&self, area: &str, limit: i32, offset: i32) -> Result<Vec<Album>, failure::Error> {
         let url = format!("/weapi/album/new");
         let mut params = HashMap::new();
-        params.insert("area".to_owned(), "ALL".to_string());
+        params.insert("area".to_owned(), area.to_string());
         params.insert("limit".to_owned(), limit.to_string());
         params.insert("offset".to_owned(), offset.to_string());
         params.insert("total".to_owned(), true.to_string());
@@ -633,7 +1041,7 @@ impl CloudMusic {
     }
 
     // like track
-    pub fn like(&self, track_id: &str, like: bool) -> Result<String, failure::Error> {
+    pub fn like(&self, track_id: &str, like: bool) -> Result<(), failure::Error> {
         let url = format!("/weapi/radio/like");
         let mut params = HashMap::new();
         params.insert("alg".to_owned(), "itembased".to_owned());
@@ -642,8 +1050,42 @@ impl CloudMusic {
         params.insert("time".to_owned(), "25".to_owned());
 
         let result = self.post(&url, &mut params)?;
-        info!("{:#?}", result);
-        Ok("ok".to_string())
+        self.check_code(&result, "like track failed")
+    }
+
+    // play ranking of user, weekly or all time
+    pub fn play_record(&self, uid: &str, week: bool) -> Result<Vec<PlayRecord>, failure::Error> {
+        let url = "/weapi/v1/play/record";
+        let mut params = HashMap::new();
+        params.insert("uid".to_owned(), uid.to_string());
+        params.insert("type".to_owned(), if week { "1" } else { "0" }.to_string());
+
+        let result = self.post(url, &mut params)?;
+        let records = match self.convert_result::<PlayRecordRes>(&result) {
+            Ok(PlayRecordRes {
+                weekData: Some(records),
+                ..
+            }) if week => records,
+            Ok(PlayRecordRes {
+                allData: Some(records),
+                ..
+            }) if !week => records,
+            _ => return Err(err_msg("get play record failed")),
+        };
+        Ok(records)
+    }
+
+    // ids of all liked tracks
+    pub fn liked_ids(&self, uid: &str) -> Result<Vec<i64>, failure::Error> {
+        let url = "/weapi/song/like/get";
+        let mut params = HashMap::new();
+        params.insert("uid".to_owned(), uid.to_string());
+
+        let result = self.post(url, &mut params)?;
+        match self.convert_result::<LikedIdsRes>(&result) {
+            Ok(LikedIdsRes { ids: Some(ids), .. }) => Ok(ids),
+            _ => Err(err_msg("get liked tracks failed")),
+        }
     }
 
     // like track
@@ -674,6 +1116,124 @@ impl CloudMusic {
         Ok("ok".to_string())
     }
 
+    // create playlist, return playlist id
+    pub fn create_playlist(&self, name: &str, private: bool) -> Result<i64, failure::Error> {
+        let url = "/weapi/playlist/create";
+        let mut params = HashMap::new();
+        params.insert("name".to_owned(), name.to_string());
+        // 0 is public, 10 is private
+        let privacy = if private { 10 } else { 0 };
+        params.insert("privacy".to_owned(), privacy.to_string());
+        params.insert("type".to_owned(), "NORMAL".to_string());
+
+        let result = self.post(url, &mut params)?;
+        match self.convert_result::<PlaylistCreateRes>(&result) {
+            Ok(PlaylistCreateRes { id: Some(id), .. }) => Ok(id),
+            _ => Err(err_msg("create playlist failed")),
+        }
+    }
+
+    pub fn delete_playlist(&self, playlist_id: &str) -> Result<(), failure::Error> {
+        let url = "/weapi/playlist/remove";
+        let mut params = HashMap::new();
+        params.insert("ids".to_owned(), format!("[{}]", playlist_id));
+
+        let result = self.post(url, &mut params)?;
+        self.check_code(&result, "delete playlist failed")
+    }
+
+    pub fn rename_playlist(&self, playlist_id: &str, name: &str) -> Result<(), failure::Error> {
+        let url = "/weapi/playlist/update/name";
+        let mut params = HashMap::new();
+        params.insert("id".to_owned(), playlist_id.to_string());
+        params.insert("name".to_owned(), name.to_string());
+
+        let result = self.post(url, &mut params)?;
+        self.check_code(&result, "rename playlist failed")
+    }
+
+    // add or delete tracks of playlist
+    pub fn playlist_tracks(&self, playlist_id: &str, track_ids: &[i64], add: bool) -> Result<(), failure::Error> {
+        let op = match add {
+            true => "add",
+            false => "del",
+        };
+        self.manipulate_tracks(playlist_id, track_ids, op)
+    }
+
+    fn manipulate_tracks(&self, playlist_id: &str, track_ids: &[i64], op: &str) -> Result<(), failure::Error> {
+        let url = "/weapi/playlist/manipulate/tracks";
+        let mut params = HashMap::new();
+        params.insert("op".to_owned(), op.to_string());
+        params.insert("pid".to_owned(), playlist_id.to_string());
+        params.insert("trackIds".to_owned(), serde_json::to_string(track_ids)?);
+        params.insert("imme".to_owned(), true.to_string());
+
+        let result = self.post(url, &mut params)?;
+        self.check_code(&result, &format!("{} playlist tracks failed", op))
+    }
+
+    // comments of song, album, playlist or dj program
+    // hot comments or latest comments, return comments and total count
+    pub fn comments(
+        &self,
+        thread_id: &str,
+        hot: bool,
+        limit: i32,
+        offset: i32,
+    ) -> Result<(Vec<Comment>, i32), failure::Error> {
+        let url = match hot {
+            true => format!("/weapi/v1/resource/hotcomments/{}", thread_id),
+            false => format!("/weapi/v1/resource/comments/{}", thread_id),
+        };
+        let mut params = HashMap::new();
+        params.insert("rid".to_owned(), thread_id.to_string());
+        params.insert("limit".to_owned(), limit.to_string());
+        params.insert("offset".to_owned(), offset.to_string());
+
+        let result = self.post(&url, &mut params)?;
+        match self.convert_result::<CommentRes>(&result) {
+            Ok(res) => {
+                let comments = match hot {
+                    true => res.hotComments,
+                    false => res.comments,
+                };
+                Ok((comments.unwrap_or_default(), res.total.unwrap_or(0)))
+            }
+            Err(_) => Err(err_msg("get comments failed")),
+        }
+    }
+
+    pub fn like_comment(&self, thread_id: &str, comment_id: i64, like: bool) -> Result<(), failure::Error> {
+        let url = match like {
+            true => "/weapi/v1/comment/like",
+            false => "/weapi/v1/comment/unlike",
+        };
+        let mut params = HashMap::new();
+        params.insert("threadId".to_owned(), thread_id.to_string());
+        params.insert("commentId".to_owned(), comment_id.to_string());
+
+        let result = self.post(url, &mut params)?;
+        self.check_code(&result, "like comment failed")
+    }
+
+    // post comment, or reply to a comment
+    pub fn post_comment(&self, thread_id: &str, content: &str, reply_to: Option<i64>) -> Result<(), failure::Error> {
+        let mut params = HashMap::new();
+        params.insert("threadId".to_owned(), thread_id.to_string());
+        params.insert("content".to_owned(), content.to_string());
+        let url = match reply_to {
+            Some(comment_id) => {
+                params.insert("commentId".to_owned(), comment_id.to_string());
+                "/weapi/resource/comments/reply"
+            }
+            None => "/weapi/resource/comments/add",
+        };
+
+        let result = self.post(url, &mut params)?;
+        self.check_code(&result, "post comment failed")
+    }
+
     // dj sublist
     pub fn dj_sublist(&self, limit: i32, offset: i32) -> Result<Vec<DjRadio>, failure::Error> {
         let url = format!("/weapi/djradio/get/subed");
@@ -724,6 +1284,14 @@ impl CloudMusic {
         }
     }
 
+    // check code of response for api without data
+    fn check_code(&self, result: &str, error: &str) -> Result<(), failure::Error> {
+        match self.convert_result::<ApiCode>(result)?.code {
+            Some(200) => Ok(()),
+            _ => Err(format_err!("{}", error)),
+        }
+    }
+
     pub fn convert_result<'a, T: Deserialize<'a>>(
         &self,
         input: &'a str,
@@ -739,15 +1307,21 @@ impl CloudMusic {
     }
 }
 
-pub fn convert_map_to_string<K: Debug + Eq + Hash + ToString, V: Debug + ToString>(
-    map: &HashMap<K, V>,
-) -> String {
-    let mut string: String = String::new();
-    for (key, value) in map.iter() {
-        string.push_str(&key.to_string());
-        string.push_str("=");
-        string.push_str(&value.to_string());
-        string.push_str("&");
-    }
-    string
+// readable login error from api code
+pub fn login_error(code: i32, message: Option<String>) -> failure::Error {
+    let reason = match code {
+        400 => "Invalid request, please check your input".to_owned(),
+        501 => "Account does not exist".to_owned(),
+        502 => "Wrong password".to_owned(),
+        503 => "Wrong verification code".to_owned(),
+        509 => "Too many attempts, please try again later".to_owned(),
+        8821 => "Need verification, please login with QR code or SMS".to_owned(),
+        _ => message.unwrap_or_else(|| "Login failed".to_owned()),
+    };
+    format_err!("{} (code {})", reason, code)
+}
+
+// url encoded in login qrcode
+pub fn qr_login_url(unikey: &str) -> String {
+    format!("https://music.163.com/login?codekey={}", unikey)
 }
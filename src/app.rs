@@ -2,13 +2,27 @@ use super::api::CloudMusic;
 use super::handlers::TrackState;
 use super::model::album::Album;
 use super::model::artist::Artist;
+use super::model::comment::{Comment, CommentType};
 use super::model::dj::{DjProgram, DjRadio};
 use super::model::lyric::Lyric;
-use super::model::playlist::{Playlist, Track};
+use super::model::personalized::{PersonalizedPlaylist, PersonalizedProgram};
+use super::model::playlist::{Playlist, PlaylistCategory, Toplist, Track};
+use super::model::search::{LyricTrack, SearchSuggest, SuggestItem};
+use super::model::user::{Profile, User};
 use super::player::Nplayer;
 use super::ui::circle::{Circle, CIRCLE, CIRCLE_TICK};
+use super::util::paths::{self, Dir};
+use super::util::query::SearchQuery;
+use super::util::recent::RecentTracks;
+use super::util::search_history::SearchHistory;
+use super::util::settings::{self, ProfileConfig};
 
 use rand::Rng;
+use std::collections::{HashMap, HashSet};
+use std::mem;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
 use tui::layout::Rect;
 use tui::style::Color;
 
@@ -18,16 +32,48 @@ const DEFAULT_ROUTE: Route = Route {
     hovered_block: ActiveBlock::Recommend,
 };
 
-pub const RECOMMEND_OPTIONS: [&str; 6] = [
+// comments per page
+const COMMENT_PAGE_SIZE: i32 = 20;
+
+// search results per page
+const SEARCH_PAGE_SIZE: i32 = 30;
+
+// number of queries to keep results
+const SEARCH_CACHE_SIZE: usize = 10;
+
+// fetch suggestions after typing pauses for a while
+const SUGGEST_DELAY: Duration = Duration::from_millis(300);
+
+// played personal fm tracks to keep
+const FM_HISTORY_SIZE: usize = 50;
+
+// max rows of suggestion dropdown
+pub const MAX_SUGGESTIONS: usize = 10;
+
+// category of all playlists
+const ALL_CATEGORY: &str = "全部";
+
+// refresh login token every hour to keep session alive
+const TOKEN_REFRESH_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+// request to retry after login again
+type Retry = Box<dyn FnOnce(&mut App)>;
+
+pub const RECOMMEND_OPTIONS: [&str; 11] = [
     "My Playlist",
+    "Liked Songs",
+    "Daily Recommend",
     "Discover",
     "Personal FM",
-    "Hot Albums",
+    "New Releases",
     "Hot Artists",
     "Subed DjRadios",
+    "Charts",
+    "History",
+    "My Profile",
 ];
 
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum Action {
     Subscribe,
     Unsubscribe,
@@ -56,6 +102,15 @@ pub enum RouteId {
     Playing,
     DjRadio,
     DjProgram,
+    Login,
+    Profiles,
+    PlaylistPicker,
+    Comments,
+    User,
+    Discover,
+    History,
+    Charts,
+    CategoryPicker,
 }
 
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -80,13 +135,48 @@ pub enum ActiveBlock {
     Msg,
     DjRadio,
     DjProgram,
+    Login,
+    Profiles,
+    PlaylistPicker,
+    Comments,
+    Dialog,
+    User,
+    Discover,
+    History,
+    Charts,
+    CategoryPicker,
 }
 
-#[derive(Clone)]
-pub struct ArtistAlbums {
-    pub artist_name: String,
+// tabs of artist page
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum ArtistTab {
+    #[default]
+    Songs,
+    Albums,
+    Similar,
+}
+
+impl ArtistTab {
+    pub fn next(self) -> ArtistTab {
+        match self {
+            ArtistTab::Songs => ArtistTab::Albums,
+            ArtistTab::Albums => ArtistTab::Similar,
+            ArtistTab::Similar => ArtistTab::Songs,
+        }
+    }
+}
+
+// artist info, top songs, albums and similar artists
+#[derive(Clone, Debug, Default)]
+pub struct ArtistDetail {
+    pub artist: Artist,
+    pub tab: ArtistTab,
+    pub songs: Vec<Track>,
     pub albums: Vec<Album>,
-    pub selected_index: usize,
+    pub similar: Vec<Artist>,
+    pub selected_song_index: usize,
+    pub selected_album_index: usize,
+    pub selected_similar_index: usize,
 }
 
 #[derive(Clone)]
@@ -102,6 +192,18 @@ pub struct PlaylistTable {
     pub playlists: Vec<Playlist>,
     pub selected_index: usize,
     pub selected_page: usize,
+    pub category: String,
+    // order by hot or new
+    pub hot: bool,
+}
+
+// choose category of top playlists
+#[derive(Clone, Debug)]
+pub struct CategoryPicker {
+    pub categories: Vec<PlaylistCategory>,
+    // group name by category id
+    pub groups: HashMap<String, String>,
+    pub selected_index: usize,
 }
 
 // album list
@@ -110,14 +212,84 @@ pub struct AlbumsTable {
     pub albums: Vec<Album>,
     pub selected_index: usize,
     pub selected_page: usize,
+    // index of ALBUM_AREAS
+    pub area: usize,
 }
 
+// area names of new albums with api value
+pub const ALBUM_AREAS: [(&str, &str); 5] = [
+    ("All", "ALL"),
+    ("Chinese", "ZH"),
+    ("Western", "EA"),
+    ("Korean", "KR"),
+    ("Japanese", "JP"),
+];
+
 // album list
 #[derive(Clone)]
 pub struct ArtistsTable {
     pub artists: Vec<Artist>,
     pub selected_index: usize,
     pub selected_page: usize,
+    pub filter: ArtistFilter,
+}
+
+// area and type names with api value
+pub const ARTIST_AREAS: [(&str, i32); 6] = [
+    ("All", -1),
+    ("Chinese", 7),
+    ("Western", 96),
+    ("Japanese", 8),
+    ("Korean", 16),
+    ("Other", 0),
+];
+pub const ARTIST_TYPES: [(&str, i32); 4] = [("All", -1), ("Male", 1), ("Female", 2), ("Band", 3)];
+
+// filters of artist directory, default shows hot artists
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct ArtistFilter {
+    // index of ARTIST_AREAS
+    pub area: usize,
+    // index of ARTIST_TYPES
+    pub kind: usize,
+    pub initial: Option<char>,
+}
+
+impl ArtistFilter {
+    pub fn next_area(self) -> ArtistFilter {
+        ArtistFilter {
+            area: (self.area + 1) % ARTIST_AREAS.len(),
+            ..self
+        }
+    }
+
+    pub fn next_kind(self) -> ArtistFilter {
+        ArtistFilter {
+            kind: (self.kind + 1) % ARTIST_TYPES.len(),
+            ..self
+        }
+    }
+
+    // cycle all, A to Z
+    pub fn next_initial(self, forward: bool) -> ArtistFilter {
+        let initial = match (self.initial, forward) {
+            (None, true) => Some('A'),
+            (None, false) => Some('Z'),
+            (Some('Z'), true) | (Some('A'), false) => None,
+            (Some(c), true) => Some((c as u8 + 1) as char),
+            (Some(c), false) => Some((c as u8 - 1) as char),
+        };
+        ArtistFilter { initial, ..self }
+    }
+
+    // api use char code of upper case letter
+    pub fn initial_code(self) -> i32 {
+        self.initial.map_or(-1, |c| c as i32)
+    }
+
+    pub fn is_default(self) -> bool {
+        self == ArtistFilter::default()
+    }
 }
 
 #[derive(Clone, Debug, Default)]
@@ -125,6 +297,8 @@ pub struct TrackTable {
     pub tracks: Vec<Track>,
     pub selected_index: usize,
     pub name: String,
+    // tracks of playlist, None for album, search result, etc.
+    pub playlist_id: Option<i64>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -141,29 +315,394 @@ pub struct ProgramTable {
     pub name: String,
 }
 
+// comments of song, album, playlist or dj program
+#[derive(Clone, Debug)]
+pub struct CommentTable {
+    pub thread_id: String,
+    pub title: String,
+    // hot comments or latest comments
+    pub hot: bool,
+    pub comments: Vec<Comment>,
+    pub total: i32,
+    pub selected_index: usize,
+    pub selected_page: usize,
+}
+
+// what to do with input of dialog
+#[derive(Clone, Debug, PartialEq)]
+pub enum DialogAction {
+    Comment {
+        thread_id: String,
+        reply_to: Option<i64>,
+    },
+    CreatePlaylist {
+        private: bool,
+    },
+    RenamePlaylist {
+        playlist_id: i64,
+    },
+    // input yes to confirm
+    DeletePlaylist {
+        playlist_id: i64,
+    },
+}
+
+// input dialog, submit with enter
+#[derive(Clone, Debug)]
+pub struct Dialog {
+    pub title: String,
+    pub input: String,
+    pub action: DialogAction,
+}
+
+// user detail and playlists
+#[derive(Clone, Debug)]
+pub struct UserPage {
+    pub user_id: i64,
+    pub user: User,
+    pub playlists: Vec<Playlist>,
+    pub selected_index: usize,
+}
+
+// sections of discover page, switch with tab
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum DiscoverSection {
+    #[default]
+    Playlists,
+    Songs,
+    Programs,
+}
+
+impl DiscoverSection {
+    pub fn next(self) -> DiscoverSection {
+        match self {
+            DiscoverSection::Playlists => DiscoverSection::Songs,
+            DiscoverSection::Songs => DiscoverSection::Programs,
+            DiscoverSection::Programs => DiscoverSection::Playlists,
+        }
+    }
+}
+
+// personalized recommend playlists, new songs and dj programs
+#[derive(Clone, Debug, Default)]
+pub struct Discover {
+    pub playlists: Vec<PersonalizedPlaylist>,
+    pub songs: Vec<Track>,
+    pub programs: Vec<PersonalizedProgram>,
+    pub section: DiscoverSection,
+    pub selected_playlist_index: usize,
+    pub selected_song_index: usize,
+    pub selected_program_index: usize,
+}
+
+// official charts
+#[derive(Clone, Debug, Default)]
+pub struct ChartTable {
+    pub charts: Vec<Toplist>,
+    pub selected_index: usize,
+}
+
+// tabs of history page
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum HistoryTab {
+    #[default]
+    Recent,
+    Week,
+    All,
+}
+
+impl HistoryTab {
+    pub fn next(self) -> HistoryTab {
+        match self {
+            HistoryTab::Recent => HistoryTab::Week,
+            HistoryTab::Week => HistoryTab::All,
+            HistoryTab::All => HistoryTab::Recent,
+        }
+    }
+}
+
+// recently played tracks or play ranking of user
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    pub tab: HistoryTab,
+    pub tracks: Vec<Track>,
+    // play count of ranking, empty for recent tracks
+    pub play_counts: Vec<i32>,
+    pub selected_index: usize,
+}
+
+// choose own playlist to add track
+#[derive(Clone, Debug)]
+pub struct PlaylistPicker {
+    pub track: Track,
+    pub playlists: Vec<Playlist>,
+    pub selected_index: usize,
+}
+
+// profile list
+#[derive(Clone, Debug, Default)]
+pub struct ProfileTable {
+    pub profiles: Vec<ProfileConfig>,
+    pub selected_index: usize,
+}
+
+// qrcode login state
+#[derive(Clone, Debug, Default)]
+pub struct QrLogin {
+    pub unikey: String,
+    pub status: String,
+    pub tick: usize,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum LoginMode {
+    #[default]
+    QrCode,
+    Password,
+    Sms,
+}
+
+// login page input
+#[derive(Clone, Debug, Default)]
+pub struct LoginForm {
+    pub mode: LoginMode,
+    // cellphone or email
+    pub account: String,
+    pub password: String,
+    pub captcha: String,
+    pub selected_field: usize,
+    pub error: Option<String>,
+    pub info: Option<String>,
+}
+
+impl LoginForm {
+    // input fields of current mode
+    pub fn field_count(&self) -> usize {
+        match self.mode {
+            LoginMode::QrCode => 0,
+            LoginMode::Password | LoginMode::Sms => 2,
+        }
+    }
+
+    pub fn selected_input(&mut self) -> Option<&mut String> {
+        match (self.mode, self.selected_field) {
+            (LoginMode::QrCode, _) => None,
+            (_, 0) => Some(&mut self.account),
+            (LoginMode::Password, _) => Some(&mut self.password),
+            (LoginMode::Sms, _) => Some(&mut self.captcha),
+        }
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.error = Some(error);
+        self.info = None;
+    }
+
+    pub fn set_info(&mut self, info: String) {
+        self.info = Some(info);
+        self.error = None;
+    }
+}
+
 #[derive(Clone)]
 pub struct Recommend {
     pub selected_index: usize,
 }
 
+// one tab of search result, None in SearchResult means not fetched yet
+#[derive(Clone)]
+pub struct SearchList<T> {
+    // items matched the query filter
+    pub items: Vec<T>,
+    // total count of all pages
+    pub total: usize,
+    // count of fetched items before filter, offset of next page
+    fetched: usize,
+    pub selected_index: usize,
+}
+
+impl<T> SearchList<T> {
+    pub fn selected(&self) -> Option<&T> {
+        self.items.get(self.selected_index)
+    }
+
+    pub fn has_more(&self) -> bool {
+        self.fetched < self.total
+    }
+
+    // append next page, empty page means no more results
+    fn append(&mut self, items: Vec<T>, keep: impl Fn(&T) -> bool, total: Option<usize>) {
+        if items.is_empty() {
+            self.total = self.fetched;
+            return;
+        }
+        self.fetched += items.len();
+        self.items.extend(items.into_iter().filter(keep));
+        // keep last count if not returned
+        self.total = total.unwrap_or(self.total).max(self.fetched);
+    }
+}
+
+// offset of next page of tab, None if all loaded
+fn next_offset<T>(list: &Option<SearchList<T>>) -> Option<i32> {
+    match list {
+        Some(list) if list.has_more() => Some(list.fetched as i32),
+        Some(_) => None,
+        None => Some(0),
+    }
+}
+
+fn append_page<T>(
+    list: &mut Option<SearchList<T>>,
+    items: Vec<T>,
+    keep: impl Fn(&T) -> bool,
+    total: Option<i32>,
+) {
+    let list = list.get_or_insert_with(|| SearchList {
+        items: vec![],
+        total: 0,
+        fetched: 0,
+        selected_index: 0,
+    });
+    list.append(items, keep, total.map(|total| total.max(0) as usize));
+}
+
+#[derive(Clone, Default)]
 pub struct SearchResult {
-    pub tracks: Option<Vec<Track>>,
-    pub playlists: Option<Vec<Playlist>>,
-    pub artists: Option<Vec<Artist>>,
-    pub albums: Option<Vec<Album>>,
-    pub djradios: Option<Vec<DjRadio>>,
+    // search input, key of cache
+    pub query: String,
+    pub filter: SearchQuery,
+    pub tracks: Option<SearchList<Track>>,
+    pub artists: Option<SearchList<Artist>>,
+    pub albums: Option<SearchList<Album>>,
+    pub playlists: Option<SearchList<Playlist>>,
+    pub djradios: Option<SearchList<DjRadio>>,
+    pub lyrics: Option<SearchList<LyricTrack>>,
+    pub users: Option<SearchList<Profile>>,
+}
+
+impl SearchResult {
+    fn new(query: String, filter: SearchQuery) -> SearchResult {
+        SearchResult {
+            query,
+            filter,
+            ..Default::default()
+        }
+    }
+
+    // matched and total count of tab, None if not fetched yet
+    pub fn counts(&self, tab: usize) -> Option<(usize, usize)> {
+        fn counts<T>(list: &Option<SearchList<T>>) -> Option<(usize, usize)> {
+            list.as_ref().map(|l| (l.items.len(), l.total))
+        }
+        match tab {
+            0 => counts(&self.tracks),
+            1 => counts(&self.artists),
+            2 => counts(&self.albums),
+            3 => counts(&self.playlists),
+            4 => counts(&self.djradios),
+            5 => counts(&self.lyrics),
+            6 => counts(&self.users),
+            _ => None,
+        }
+    }
+
+    fn next_offset(&self, tab: usize) -> Option<i32> {
+        match tab {
+            0 => next_offset(&self.tracks),
+            1 => next_offset(&self.artists),
+            2 => next_offset(&self.albums),
+            3 => next_offset(&self.playlists),
+            4 => next_offset(&self.djradios),
+            5 => next_offset(&self.lyrics),
+            6 => next_offset(&self.users),
+            _ => None,
+        }
+    }
+}
+
+// personal fm state, next batch is fetched in background
+#[derive(Default)]
+pub struct PersonalFm {
+    // next batch, played after current queue
+    pub upcoming: Vec<Track>,
+    // played fm tracks, newest first
+    pub history: Vec<Track>,
+    // tracks moved to trash in this session
+    pub trashed: HashSet<i64>,
+    prefetch: Option<mpsc::Receiver<Result<Vec<Track>, failure::Error>>>,
+}
+
+impl PersonalFm {
+    pub fn is_prefetching(&self) -> bool {
+        self.prefetch.is_some()
+    }
+
+    fn push_history(&mut self, track: Track) {
+        self.history.retain(|t| *t != track);
+        self.history.insert(0, track);
+        self.history.truncate(FM_HISTORY_SIZE);
+    }
+}
 
-    pub selected_albums_index: usize,
-    pub selected_artists_index: usize,
-    pub selected_playlists_index: usize,
-    pub selected_tracks_index: usize,
-    pub selected_djradio_index: usize,
+// seed of song radio
+#[derive(Clone)]
+pub struct RadioSeed {
+    pub track: Track,
+    // playlist the track played from, for intelligence mode
+    pub playlist_id: Option<i64>,
+}
+
+fn radio_tracks(
+    api: &CloudMusic,
+    seed: &RadioSeed,
+    current: &Track,
+) -> Result<Vec<Track>, failure::Error> {
+    let id = current.id.unwrap_or(0);
+    match seed.playlist_id {
+        Some(playlist_id) => api.intelligence_list(seed.track.id.unwrap_or(0), playlist_id, id),
+        None => api.similar_songs(id),
+    }
+}
+
+// dropdown under search input
+#[derive(Default)]
+pub struct SearchSuggestion {
+    // kind and keyword of each item
+    pub items: Vec<(&'static str, String)>,
+    pub selected_index: Option<usize>,
+    // last input change, cleared after fetched
+    changed_at: Option<Instant>,
+    // hot searches are fetched once
+    hots: Option<Vec<String>>,
+}
 
-    pub selected_albums_page: usize,
-    pub selected_artists_page: usize,
-    pub selected_playlists_page: usize,
-    pub selected_tracks_page: usize,
-    pub selected_djradio_page: usize,
+// keywords of suggestion, song with its artists
+fn suggestion_items(suggest: SearchSuggest) -> Vec<(&'static str, String)> {
+    let names = |items: Option<Vec<SuggestItem>>| {
+        items
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|item| item.name)
+            .collect::<Vec<String>>()
+    };
+    let mut items = vec![];
+    for song in suggest.songs.unwrap_or_default() {
+        if let Some(name) = song.name {
+            let artists = names(song.artists);
+            items.push(("Song", format!("{} {}", name, artists.join(" ")).trim().to_owned()));
+        }
+    }
+    for name in names(suggest.artists) {
+        items.push(("Artist", name));
+    }
+    for name in names(suggest.albums) {
+        items.push(("Album", name));
+    }
+    for name in names(suggest.playlists) {
+        items.push(("Playlist", name));
+    }
+    items
 }
 
 // 顺序播放
@@ -207,11 +746,21 @@ pub struct App {
     pub my_playlist: TrackTable,
     pub repeat_state: RepeatState,
     pub fm_state: bool,
+    // song radio instead of personal fm when fm_state
+    pub radio_seed: Option<RadioSeed>,
+    pub fm: PersonalFm,
     pub search_results: SearchResult,
+    // results of recent queries, newest last
+    search_cache: Vec<SearchResult>,
+    pub search_suggestion: SearchSuggestion,
+    // parse error of search input
+    pub search_error: Option<String>,
+    // recent queries of current profile
+    pub search_history: SearchHistory,
     pub tabs: TabsState,
     pub playing_circle: Circle,
     pub circle_flag: bool,
-    pub artist_albums: Option<ArtistAlbums>,
+    pub artist_detail: Option<ArtistDetail>,
     pub selected_album: Option<SelectedAlbum>,
     pub playlist_list: Option<PlaylistTable>,
     pub album_list: Option<AlbumsTable>,
@@ -225,6 +774,26 @@ pub struct App {
     pub lyric_index: usize,
     pub msg_control: usize,
     pub user_id: i32,
+    pub qr_login: Option<QrLogin>,
+    pub login_form: LoginForm,
+    token_refreshed_at: Instant,
+    // current profile name
+    pub profile: String,
+    pub profile_list: Option<ProfileTable>,
+    pub playlist_picker: Option<PlaylistPicker>,
+    pub comment_list: Option<CommentTable>,
+    pub dialog: Option<Dialog>,
+    pub user_page: Option<UserPage>,
+    pub discover: Option<Discover>,
+    // ids of liked tracks, fetched after login
+    pub liked_ids: HashSet<i64>,
+    // recently played tracks of current profile
+    pub recent: RecentTracks,
+    pub history: Option<History>,
+    pub chart_list: Option<ChartTable>,
+    pub category_picker: Option<CategoryPicker>,
+    // request failed for login expired, retried after login again
+    relogin_retry: Option<Retry>,
 }
 
 impl App {
@@ -247,33 +816,25 @@ impl App {
             my_playlist: Default::default(),
             repeat_state: RepeatState::All,
             fm_state: false,
-            search_results: SearchResult {
-                tracks: None,
-                playlists: None,
-                artists: None,
-                albums: None,
-                djradios: None,
-                selected_albums_index: 0,
-                selected_artists_index: 0,
-                selected_playlists_index: 0,
-                selected_tracks_index: 0,
-                selected_djradio_index: 0,
-                selected_albums_page: 0,
-                selected_artists_page: 0,
-                selected_playlists_page: 0,
-                selected_tracks_page: 0,
-                selected_djradio_page: 0,
-            },
+            radio_seed: None,
+            fm: Default::default(),
+            search_results: Default::default(),
+            search_cache: vec![],
+            search_suggestion: Default::default(),
+            search_error: None,
+            search_history: SearchHistory::default(),
             tabs: TabsState::new(vec![
                 "Songs".to_string(),
                 "Artists".to_string(),
                 "Albums".to_string(),
                 "Playlists".to_string(),
                 "DjRadios".to_string(),
+                "Lyrics".to_string(),
+                "Users".to_string(),
             ]),
             playing_circle: Circle::default(),
             circle_flag: true,
-            artist_albums: None,
+            artist_detail: None,
             selected_album: None,
             playlist_list: None,
             djradio_list: None,
@@ -287,6 +848,22 @@ impl App {
             lyric_index: 0,
             msg_control: 0,
             user_id: 0,
+            qr_login: None,
+            login_form: Default::default(),
+            token_refreshed_at: Instant::now(),
+            profile: paths::DEFAULT_PROFILE.to_string(),
+            profile_list: None,
+            playlist_picker: None,
+            comment_list: None,
+            dialog: None,
+            user_page: None,
+            discover: None,
+            liked_ids: HashSet::new(),
+            recent: RecentTracks::default(),
+            history: None,
+            chart_list: None,
+            category_picker: None,
+            relogin_retry: None,
         }
     }
 
@@ -314,6 +891,108 @@ impl App {
                             Some(ActiveBlock::DjProgram),
                         );
                     }
+                    RouteId::Login => {
+                        self.set_current_route_state(
+                            Some(ActiveBlock::Login),
+                            Some(ActiveBlock::Login),
+                        );
+                    }
+                    RouteId::Profiles => {
+                        self.set_current_route_state(
+                            Some(ActiveBlock::Profiles),
+                            Some(ActiveBlock::Profiles),
+                        );
+                    }
+                    RouteId::Search => {
+                        self.set_current_route_state(
+                            Some(ActiveBlock::SearchResult),
+                            Some(ActiveBlock::SearchResult),
+                        );
+                    }
+                    RouteId::Comments => {
+                        self.set_current_route_state(
+                            Some(ActiveBlock::Comments),
+                            Some(ActiveBlock::Comments),
+                        );
+                    }
+                    RouteId::Artist => {
+                        self.set_current_route_state(
+                            Some(ActiveBlock::Artist),
+                            Some(ActiveBlock::Artist),
+                        );
+                    }
+                    RouteId::User => {
+                        self.set_current_route_state(
+                            Some(ActiveBlock::User),
+                            Some(ActiveBlock::User),
+                        );
+                    }
+                    RouteId::History => {
+                        self.set_current_route_state(
+                            Some(ActiveBlock::History),
+                            Some(ActiveBlock::History),
+                        );
+                    }
+                    RouteId::PersonalFm => {
+                        self.set_current_route_state(
+                            Some(ActiveBlock::PersonalFm),
+                            Some(ActiveBlock::PersonalFm),
+                        );
+                    }
+                    RouteId::Home => {
+                        self.set_current_route_state(
+                            Some(ActiveBlock::Recommend),
+                            Some(ActiveBlock::Recommend),
+                        );
+                    }
+                    RouteId::Discover => {
+                        self.set_current_route_state(
+                            Some(ActiveBlock::Discover),
+                            Some(ActiveBlock::Discover),
+                        );
+                    }
+                    RouteId::Charts => {
+                        self.set_current_route_state(
+                            Some(ActiveBlock::Charts),
+                            Some(ActiveBlock::Charts),
+                        );
+                    }
+                    RouteId::CategoryPicker => {
+                        self.set_current_route_state(
+                            Some(ActiveBlock::CategoryPicker),
+                            Some(ActiveBlock::CategoryPicker),
+                        );
+                    }
+                    RouteId::AlbumList => {
+                        self.set_current_route_state(
+                            Some(ActiveBlock::AlbumList),
+                            Some(ActiveBlock::AlbumList),
+                        );
+                    }
+                    RouteId::DjRadio => {
+                        self.set_current_route_state(
+                            Some(ActiveBlock::DjRadio),
+                            Some(ActiveBlock::DjRadio),
+                        );
+                    }
+                    RouteId::MyPlaylists => {
+                        self.set_current_route_state(
+                            Some(ActiveBlock::MyPlaylists),
+                            Some(ActiveBlock::MyPlaylists),
+                        );
+                    }
+                    RouteId::ArtistList => {
+                        self.set_current_route_state(
+                            Some(ActiveBlock::ArtistList),
+                            Some(ActiveBlock::ArtistList),
+                        );
+                    }
+                    RouteId::PlaylistPicker => {
+                        self.set_current_route_state(
+                            Some(ActiveBlock::PlaylistPicker),
+                            Some(ActiveBlock::PlaylistPicker),
+                        );
+                    }
                     _ => {
                         self.set_current_route_state(
                             Some(ActiveBlock::TrackTable),
@@ -326,6 +1005,21 @@ impl App {
                 self.msg_control += 1;
             }
         }
+        if self.get_current_route().id == RouteId::Login
+            && self.login_form.mode == LoginMode::QrCode
+        {
+            self.check_qr_login();
+        }
+        if self.get_current_route().active_block == ActiveBlock::Search {
+            self.update_search_suggestion();
+        }
+        self.poll_fm_prefetch(false);
+        if self.user_id != 0 && self.token_refreshed_at.elapsed() >= TOKEN_REFRESH_INTERVAL {
+            self.refresh_token();
+        }
+        if self.session_expired() && self.get_current_route().id != RouteId::Login {
+            self.relogin();
+        }
         if self.player.is_playing() {
             // get positon
             self.song_progress_ms = match self.player.get_position() {
@@ -451,18 +1145,31 @@ impl App {
                 }
             }
             true => {
-                // use my playlist for play personal fm
-                let list = &mut self.my_playlist;
+                // use my playlist for play personal fm or song radio
+                let list = &self.my_playlist;
+                let forward = state == TrackState::Forword;
                 let next_index = App::next_index(&list.tracks, Some(list.selected_index), state);
-                if next_index == 0 {
-                    if let Ok(tracks) = self.cloud_music.as_ref().unwrap().personal_fm() {
-                        list.tracks = tracks.to_owned();
+                if next_index == 0 && forward {
+                    match self.take_fm_batch() {
+                        Ok(tracks) if !tracks.is_empty() => self.my_playlist.tracks = tracks,
+                        Ok(_) => {}
+                        Err(e) => {
+                            self.handle_error(e);
+                            return;
+                        }
                     }
                 }
+                let list = &mut self.my_playlist;
+                let track_playing = match list.tracks.get(next_index) {
+                    Some(track) => track.to_owned(),
+                    None => {
+                        self.msg = "no more tracks to play".to_string();
+                        self.set_current_route_state(Some(ActiveBlock::Msg), None);
+                        return;
+                    }
+                };
                 list.selected_index = next_index;
-
-                let track_playing = list.tracks.get(next_index.to_owned()).unwrap().to_owned();
-                self.start_playback(track_playing);
+                self.play_fm_track(track_playing);
             }
         }
     }
@@ -534,43 +1241,115 @@ impl App {
         match &self.cloud_music {
             Some(api) => {
                 if let Ok(playlist_tracks) = api.playlist_detail(&playlist_id) {
+                    // only toplist has last rank
+                    let last_ranks = playlist_tracks
+                        .trackIds
+                        .unwrap_or_default()
+                        .into_iter()
+                        .filter_map(|t| t.lr.map(|lr| (t.id, lr)))
+                        .collect::<HashMap<i64, i32>>();
                     let tracks = playlist_tracks
                         .tracks
                         .into_iter()
-                        .map(|t| Track {
-                            name: t.name,
-                            fee: t.fee,
-                            id: t.id,
-                            artists: t.ar,
-                            album: t.al,
+                        .map(|t| {
+                            let mut track = Track::from(t);
+                            track.last_rank = track.id.and_then(|id| last_ranks.get(&id).cloned());
+                            track
                         })
                         .collect();
                     self.track_table = TrackTable {
                         tracks: tracks,
                         name: playlist_tracks.name.unwrap(),
                         selected_index: 0,
+                        playlist_id: playlist_tracks.id,
                     }
                 }
             }
             None => {}
         }
+        if self.session_expired() {
+            // the page is opened when retried after login
+            self.retry_after_login(move |app| app.get_playlist_tracks(playlist_id));
+            return;
+        }
         self.push_navigation_stack(RouteId::TrackTable, ActiveBlock::TrackTable)
     }
 
-    pub fn get_artist_albums(&mut self, artist_id: String) {
-        match &self.cloud_music {
-            Some(api) => {
-                if let Ok(albums) = api.artist_albums(&artist_id) {
-                    self.artist_albums = Some(ArtistAlbums {
-                        artist_name: String::new(),
-                        albums: albums,
-                        selected_index: 0,
-                    })
-                }
+    // daily recommend songs show in track table
+    pub fn get_recommend_songs(&mut self) {
+        let result = match &self.cloud_music {
+            Some(api) => api.recommend_songs(),
+            None => return,
+        };
+        match result {
+            Ok(tracks) => {
+                self.track_table = TrackTable {
+                    tracks,
+                    selected_index: 0,
+                    name: "Daily Recommend".to_string(),
+                    playlist_id: None,
+                };
+                self.push_navigation_stack(RouteId::TrackTable, ActiveBlock::TrackTable);
+            }
+            Err(e) => self.handle_error(e),
+        }
+        self.retry_after_login(move |app| app.get_recommend_songs());
+    }
+
+    // artist page, similar artists are optional since they need login
+    pub fn show_artist(&mut self, artist_id: String) {
+        let result = match &self.cloud_music {
+            Some(api) => api.artist_detail(&artist_id).and_then(|(artist, songs)| {
+                let albums = api.artist_albums(&artist_id)?;
+                let similar = api.similar_artists(&artist_id).unwrap_or_else(|e| {
+                    error!("{}", e);
+                    vec![]
+                });
+                Ok(ArtistDetail {
+                    artist,
+                    songs,
+                    albums,
+                    similar,
+                    ..Default::default()
+                })
+            }),
+            None => return,
+        };
+        match result {
+            Ok(artist_detail) => {
+                self.artist_detail = Some(artist_detail);
                 self.push_navigation_stack(RouteId::Artist, ActiveBlock::Artist);
             }
-            None => {}
+            Err(e) => self.handle_error(e),
+        }
+        self.retry_after_login(move |app| app.show_artist(artist_id));
+    }
+
+    pub fn follow_artist(&mut self, action: Action) {
+        let artist = match &self.artist_detail {
+            Some(artist_detail) => artist_detail.artist.to_owned(),
+            None => return,
+        };
+        let follow = action == Action::Subscribe;
+        let result = match &self.cloud_music {
+            Some(api) => api.follow_artist(&artist.id.to_string(), follow),
+            None => return,
+        };
+        match result {
+            Ok(_) => {
+                if let Some(artist_detail) = &mut self.artist_detail {
+                    artist_detail.artist.followed = Some(follow);
+                }
+                self.msg = if follow {
+                    format!("follow {}", artist.name)
+                } else {
+                    format!("unfollow {}", artist.name)
+                };
+                self.set_current_route_state(Some(ActiveBlock::Msg), None);
+            }
+            Err(e) => self.handle_error(e),
         }
+        self.retry_after_login(move |app| app.follow_artist(action));
     }
 
     pub fn get_album_tracks(&mut self, album_id: String) {
@@ -583,56 +1362,198 @@ impl App {
                         selected_index: 0,
                     })
                 }
-                self.push_navigation_stack(RouteId::AlbumTracks, ActiveBlock::AlbumTracks);
             }
-            None => {}
+            None => return,
+        }
+        if self.session_expired() {
+            // the page is opened when retried after login
+            self.retry_after_login(move |app| app.get_album_tracks(album_id));
+            return;
         }
+        self.push_navigation_stack(RouteId::AlbumTracks, ActiveBlock::AlbumTracks);
     }
 
+    // keep category and order of current list
     pub fn get_top_playlist(&mut self, limit: i32, page: i32) {
+        let (category, hot) = match &self.playlist_list {
+            Some(playlist_list) => (playlist_list.category.to_owned(), playlist_list.hot),
+            None => (ALL_CATEGORY.to_string(), true),
+        };
+        self.load_top_playlist(category, hot, limit, page);
+    }
+
+    fn load_top_playlist(&mut self, category: String, hot: bool, limit: i32, page: i32) {
         match &self.cloud_music {
             Some(api) => {
-                if let Ok(playlists) = api.top_playlists(limit, limit * page) {
+                if let Ok(playlists) = api.top_playlists(&category, hot, limit, limit * page) {
                     self.playlist_list = Some(PlaylistTable {
-                        playlists: playlists,
+                        playlists,
                         selected_index: 0,
                         selected_page: page as usize,
+                        category: category.to_owned(),
+                        hot,
                     })
                 }
             }
             None => {}
         }
+        self.retry_after_login(move |app| app.load_top_playlist(category, hot, limit, page));
     }
 
-    pub fn get_top_albums(&mut self, limit: i32, page: i32) {
-        match &self.cloud_music {
-            Some(api) => {
-                if let Ok(albums) = api.top_albums(limit, limit * page) {
-                    self.album_list = Some(AlbumsTable {
-                        albums: albums,
-                        selected_index: 0,
-                        selected_page: page as usize,
-                    })
-                }
+    // switch hot and new, back to first page
+    pub fn toggle_playlist_order(&mut self) {
+        if let Some(playlist_list) = &self.playlist_list {
+            let category = playlist_list.category.to_owned();
+            let hot = !playlist_list.hot;
+            let limit = self.block_height.saturating_sub(4).max(1) as i32;
+            self.load_top_playlist(category, hot, limit, 0);
+        }
+    }
+
+    pub fn show_category_picker(&mut self) {
+        let result = match &self.cloud_music {
+            Some(api) => api.playlist_categories(),
+            None => return,
+        };
+        match result {
+            Ok((mut categories, groups)) => {
+                // all first, then group by language, style, scene, mood and theme
+                categories.sort_by_key(|c| c.category);
+                categories.insert(
+                    0,
+                    PlaylistCategory {
+                        name: ALL_CATEGORY.to_string(),
+                        category: None,
+                        hot: None,
+                    },
+                );
+                let current = self.playlist_list.as_ref().map(|p| p.category.to_owned());
+                let selected_index = categories
+                    .iter()
+                    .position(|c| Some(&c.name) == current.as_ref())
+                    .unwrap_or(0);
+                self.category_picker = Some(CategoryPicker {
+                    categories,
+                    groups,
+                    selected_index,
+                });
+                self.push_navigation_stack(RouteId::CategoryPicker, ActiveBlock::CategoryPicker);
+            }
+            Err(e) => self.handle_error(e),
+        }
+        self.retry_after_login(move |app| app.show_category_picker());
+    }
+
+    // back to top playlists of selected category
+    pub fn select_playlist_category(&mut self) {
+        let category = match &self.category_picker {
+            Some(picker) => match picker.categories.get(picker.selected_index) {
+                Some(category) => category.name.to_owned(),
+                None => return,
+            },
+            None => return,
+        };
+        let hot = match &self.playlist_list {
+            Some(playlist_list) => playlist_list.hot,
+            None => true,
+        };
+        self.pop_navigation_stack();
+        let limit = self.block_height.saturating_sub(4).max(1) as i32;
+        self.load_top_playlist(category, hot, limit, 0);
+    }
+
+    // rows of album table, area tabs take 3 rows above it
+    pub fn album_page_size(&self) -> usize {
+        self.block_height.saturating_sub(7).max(1)
+    }
+
+    // keep area of current list
+    pub fn get_top_albums(&mut self, limit: i32, page: i32) {
+        let area = match &self.album_list {
+            Some(album_list) => album_list.area,
+            None => 0,
+        };
+        self.load_albums(area, limit, page);
+    }
+
+    fn load_albums(&mut self, area: usize, limit: i32, page: i32) {
+        match &self.cloud_music {
+            Some(api) => {
+                match api.top_albums(ALBUM_AREAS[area].1, limit, limit * page) {
+                    Ok(albums) => {
+                        self.album_list = Some(AlbumsTable {
+                            albums,
+                            selected_index: 0,
+                            selected_page: page as usize,
+                            area,
+                        })
+                    }
+                    Err(e) => self.handle_error(e),
+                }
             }
             None => {}
         }
+        self.retry_after_login(move |app| app.load_albums(area, limit, page));
+    }
+
+    // next area and back to first page
+    pub fn switch_album_area(&mut self) {
+        if let Some(album_list) = &self.album_list {
+            let area = (album_list.area + 1) % ALBUM_AREAS.len();
+            let limit = self.album_page_size() as i32;
+            self.load_albums(area, limit, 0);
+        }
     }
 
     // top artist
+    // keep filter of current list
     pub fn get_top_artists(&mut self, limit: i32, page: i32) {
+        let filter = match &self.artist_list {
+            Some(artist_list) => artist_list.filter,
+            None => ArtistFilter::default(),
+        };
+        self.load_artists(filter, limit, page);
+    }
+
+    // hot artists without filter, otherwise artist directory
+    fn load_artists(&mut self, filter: ArtistFilter, limit: i32, page: i32) {
         match &self.cloud_music {
             Some(api) => {
-                if let Ok(artists) = api.top_artists(limit, limit * page) {
-                    self.artist_list = Some(ArtistsTable {
-                        artists: artists,
-                        selected_index: 0,
-                        selected_page: page as usize,
-                    })
+                let result = if filter.is_default() {
+                    api.top_artists(limit, limit * page)
+                } else {
+                    api.artist_list(
+                        ARTIST_AREAS[filter.area].1,
+                        ARTIST_TYPES[filter.kind].1,
+                        filter.initial_code(),
+                        limit,
+                        limit * page,
+                    )
+                };
+                match result {
+                    Ok(artists) => {
+                        self.artist_list = Some(ArtistsTable {
+                            artists,
+                            selected_index: 0,
+                            selected_page: page as usize,
+                            filter,
+                        })
+                    }
+                    Err(e) => self.handle_error(e),
                 }
             }
             None => {}
         }
+        self.retry_after_login(move |app| app.load_artists(filter, limit, page));
+    }
+
+    // change filter and back to first page
+    pub fn filter_artists(&mut self, change: fn(ArtistFilter) -> ArtistFilter) {
+        if let Some(artist_list) = &self.artist_list {
+            let filter = change(artist_list.filter);
+            let limit = self.block_height.saturating_sub(4).max(1) as i32;
+            self.load_artists(filter, limit, 0);
+        }
     }
 
     // get user subscribe djradio
@@ -649,6 +1570,7 @@ impl App {
             }
             None => {}
         }
+        self.retry_after_login(move |app| app.get_sub_dj_radio(limit, page));
     }
 
     // get program list
@@ -661,54 +1583,121 @@ impl App {
                     self.program_list = Some(ProgramTable {
                         dj_programs: dj_programs,
                         selected_index: 0,
-                        name: djradio.name,
+                        name: djradio.name.to_owned(),
                     })
                 }
             }
             None => {}
         }
+        self.retry_after_login(move |app| app.get_djradio_programs(djradio, limit, page));
     }
 
     pub fn like_current(&mut self, action: Action) {
-        match &self.current_playing {
-            Some(track) => match &self.cloud_music {
-                Some(api) => match action {
-                    Action::Subscribe => match api.like(&track.id.unwrap().to_string(), true) {
-                        Ok(_) => {
-                            self.msg = format!("like {}", track.name.to_owned().unwrap());
-                            self.set_current_route_state(Some(ActiveBlock::Msg), None);
-                        }
-                        Err(e) => self.handle_error(e),
-                    },
-                    Action::Unsubscribe => match api.like(&track.id.unwrap().to_string(), false) {
-                        Ok(_) => {
-                            self.msg = format!("dislike {}", track.name.to_owned().unwrap());
-                            self.set_current_route_state(Some(ActiveBlock::Msg), None);
-                        }
-                        Err(e) => self.handle_error(e),
-                    },
-                },
-                None => {}
-            },
+        let track = match &self.current_playing {
+            Some(track) => track.to_owned(),
+            None => return,
+        };
+        let (track_id, like) = match (track.id, action) {
+            (Some(track_id), Action::Subscribe) => (track_id, true),
+            (Some(track_id), Action::Unsubscribe) => (track_id, false),
+            (None, _) => return,
+        };
+        let result = match &self.cloud_music {
+            Some(api) => api.like(&track_id.to_string(), like),
+            None => return,
+        };
+        match result {
+            Ok(_) => {
+                let name = track.name.unwrap_or_default();
+                if like {
+                    self.liked_ids.insert(track_id);
+                    self.msg = format!("like {}", name);
+                } else {
+                    self.liked_ids.remove(&track_id);
+                    self.msg = format!("dislike {}", name);
+                }
+                self.set_current_route_state(Some(ActiveBlock::Msg), None);
+            }
+            Err(e) => self.handle_error(e),
+        }
+        self.retry_after_login(move |app| app.like_current(action));
+    }
+
+    // like current track or dislike it if already liked
+    pub fn toggle_like_current(&mut self) {
+        let track_id = self.current_playing.as_ref().and_then(|t| t.id);
+        match track_id {
+            Some(track_id) if self.is_liked(track_id) => self.like_current(Action::Unsubscribe),
+            Some(_) => self.like_current(Action::Subscribe),
             None => {}
         }
     }
 
+    pub fn is_liked(&self, track_id: i64) -> bool {
+        self.liked_ids.contains(&track_id)
+    }
+
+    pub fn get_liked_ids(&mut self) {
+        let result = match &self.cloud_music {
+            Some(api) => api.liked_ids(&self.user_id.to_string()),
+            None => return,
+        };
+        match result {
+            Ok(ids) => self.liked_ids = ids.into_iter().collect(),
+            Err(e) => error!("{}", e),
+        }
+    }
+
+    // liked music is a special playlist of user
+    pub fn show_liked_songs(&mut self) {
+        let playlist_id = self.playlists.as_ref().and_then(|playlists| {
+            playlists
+                .iter()
+                .find(|p| p.specialType == Some(5))
+                .and_then(|p| p.id)
+        });
+        match playlist_id {
+            Some(playlist_id) => self.get_playlist_tracks(playlist_id.to_string()),
+            None => {
+                self.msg = "liked music not found".to_string();
+                self.set_current_route_state(Some(ActiveBlock::Msg), None);
+            }
+        }
+    }
+
     // fm move to trash
     pub fn fm_trash(&mut self) {
-        match &self.current_playing {
-            Some(track) => match &self.cloud_music {
-                Some(api) => match api.fm_trash(&track.id.unwrap().to_string()) {
-                    Ok(_) => {
-                        self.msg = format!("move {} to trash", track.name.to_owned().unwrap());
-                        self.set_current_route_state(Some(ActiveBlock::Msg), None);
-                        self.skip_track(TrackState::Forword)
-                    }
-                    Err(e) => self.handle_error(e),
-                },
-                None => {}
-            },
-            None => {}
+        // trash is feedback to personal fm, not for song radio or playlist
+        if !self.fm_state || self.radio_seed.is_some() {
+            self.msg = "only personal fm track can be moved to trash".to_string();
+            self.set_current_route_state(Some(ActiveBlock::Msg), None);
+            return;
+        }
+        let track = match &self.current_playing {
+            Some(track) => track.to_owned(),
+            None => return,
+        };
+        let result = match &self.cloud_music {
+            Some(api) => api.fm_trash(&track.id.unwrap().to_string()),
+            None => return,
+        };
+        match result {
+            Ok(_) => {
+                self.fm.trashed.insert(track.id.unwrap_or(0));
+                self.msg = format!("move {} to trash", track.name.to_owned().unwrap());
+                self.set_current_route_state(Some(ActiveBlock::Msg), None);
+                // jump to prefetched batch instead of the rest of current one
+                self.poll_fm_prefetch(false);
+                if !self.fm.upcoming.is_empty() {
+                    self.my_playlist.tracks = mem::take(&mut self.fm.upcoming);
+                    self.my_playlist.selected_index = 0;
+                    let track_playing = self.my_playlist.tracks[0].to_owned();
+                    self.play_fm_track(track_playing);
+                } else {
+                    self.skip_track(TrackState::Forword)
+                }
+            }
+            Err(e) => self.handle_error(e),
         }
     }
 
@@ -758,13 +1747,21 @@ impl App {
             },
             None => {}
         }
+        self.retry_after_login(move |app| app.subscribe_playlist(playlist, action));
     }
 
     pub fn start_playback(&mut self, track: Track) {
         match &self.cloud_music {
             Some(api) => {
                 let id = track.id.unwrap().to_string();
-                let song = api.get_song_url(&id).unwrap();
+                let song = match api.get_song_url(&id) {
+                    Ok(song) => song,
+                    Err(e) => {
+                        self.handle_error(e);
+                        self.retry_after_login(move |app| app.start_playback(track));
+                        return;
+                    }
+                };
                 info!("{:#?}", song);
                 match song.url {
                     Some(url) => {
@@ -774,7 +1771,11 @@ impl App {
                         self.song_progress_ms = 0;
                         self.lyric_index = 0;
                         self.player.play_url(&url);
-                        self.lyric = Some(api.lyric(&id).unwrap());
+                        self.lyric = Some(api.lyric(&id).unwrap_or_default());
+                        self.recent.push(track.to_owned());
+                        if let Err(e) = self.recent.save() {
+                            error!("save recent tracks failed: {}", e);
+                        }
                         self.current_playing = Some(track);
 
                         let mut flag = false;
@@ -809,23 +1810,156 @@ impl App {
     }
 
     pub fn set_fm_mode(&mut self) {
-        match &self.cloud_music {
-            Some(api) => {
-                if let Ok(tracks) = api.personal_fm() {
-                    self.my_playlist = TrackTable {
-                        tracks: tracks,
-                        selected_index: 0,
-                        name: "personal fm".to_string(),
+        self.radio_seed = None;
+        let tracks = match self.take_fm_batch() {
+            Ok(tracks) => tracks,
+            Err(e) => {
+                self.handle_error(e);
+                return;
+            }
+        };
+        let track_playing = match tracks.first() {
+            Some(track) => track.to_owned(),
+            None => return,
+        };
+        self.my_playlist = TrackTable {
+            tracks,
+            selected_index: 0,
+            name: "personal fm".to_string(),
+            playlist_id: None,
+        };
+        self.fm_state = true;
+        self.play_fm_track(track_playing);
+        self.push_navigation_stack(RouteId::PersonalFm, ActiveBlock::PersonalFm);
+    }
+
+    // play track of fm queue and get next batch ready
+    fn play_fm_track(&mut self, track: Track) {
+        if self.radio_seed.is_none() {
+            self.fm.push_history(track.to_owned());
+        }
+        self.start_playback(track);
+        self.prefetch_fm();
+    }
+
+    // fetch next personal fm batch in background if nothing is ready
+    fn prefetch_fm(&mut self) {
+        if self.radio_seed.is_some() || !self.fm.upcoming.is_empty() || self.fm.is_prefetching() {
+            return;
+        }
+        let api = match &self.cloud_music {
+            Some(api) => api.to_owned(),
+            None => return,
+        };
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            // receiver is gone when fm is stopped, nothing to do
+            let _ = tx.send(api.personal_fm());
+        });
+        self.fm.prefetch = Some(rx);
+    }
+
+    // collect prefetched batch, wait for it if needed now
+    fn poll_fm_prefetch(&mut self, wait: bool) {
+        let result = match &self.fm.prefetch {
+            Some(rx) => {
+                let received = if wait {
+                    rx.recv().map_err(|_| mpsc::TryRecvError::Disconnected)
+                } else {
+                    rx.try_recv()
+                };
+                match received {
+                    Ok(result) => result,
+                    Err(mpsc::TryRecvError::Empty) => return,
+                    Err(mpsc::TryRecvError::Disconnected) => {
+                        Err(format_err!("personal fm prefetch stopped"))
                     }
                 }
+            }
+            None => return,
+        };
+        self.fm.prefetch = None;
+        match result {
+            Ok(tracks) => {
+                let trashed = &self.fm.trashed;
+                self.fm.upcoming = tracks
+                    .into_iter()
+                    .filter(|t| !trashed.contains(&t.id.unwrap_or(0)))
+                    .collect();
+            }
+            Err(e) => error!("prefetch personal fm failed: {}", e),
+        }
+    }
 
-                let track_playing = self.my_playlist.tracks.get(0).unwrap().to_owned();
-                self.start_playback(track_playing);
+    // next batch of fm queue, prefetched one if any
+    fn take_fm_batch(&mut self) -> Result<Vec<Track>, failure::Error> {
+        if self.radio_seed.is_none() {
+            if self.fm.upcoming.is_empty() {
+                self.poll_fm_prefetch(true);
+            }
+            if !self.fm.upcoming.is_empty() {
+                return Ok(mem::take(&mut self.fm.upcoming));
+            }
+        }
+        self.next_fm_tracks()
+    }
 
-                self.push_navigation_stack(RouteId::PersonalFm, ActiveBlock::PersonalFm);
+    // endless similar songs starting from track
+    // intelligence mode when track is played from a playlist
+    pub fn start_radio(&mut self, track: Track, playlist_id: Option<i64>) {
+        let seed = RadioSeed {
+            track: track.to_owned(),
+            playlist_id,
+        };
+        let result = match &self.cloud_music {
+            Some(api) => radio_tracks(api, &seed, &track),
+            None => return,
+        };
+        match result {
+            Ok(tracks) => {
+                let name = track.name.to_owned().unwrap_or_default();
+                let mut queue = vec![track.to_owned()];
+                queue.extend(tracks.into_iter().filter(|t| *t != track));
+                self.my_playlist = TrackTable {
+                    tracks: queue,
+                    selected_index: 0,
+                    name: format!("radio of {}", name),
+                    playlist_id: None,
+                };
+                self.radio_seed = Some(seed);
+                self.start_playback(track.to_owned());
                 self.fm_state = true;
+                self.msg = format!("start radio of {}", name);
+                self.set_current_route_state(Some(ActiveBlock::Msg), None);
             }
-            None => {}
+            Err(e) => self.handle_error(e),
+        }
+        self.retry_after_login(move |app| app.start_radio(track, playlist_id));
+    }
+
+    // tracks to refill fm queue, song radio continues from current track
+    fn next_fm_tracks(&self) -> Result<Vec<Track>, failure::Error> {
+        let api = match &self.cloud_music {
+            Some(api) => api,
+            None => return Ok(vec![]),
+        };
+        let seed = match &self.radio_seed {
+            Some(seed) => seed,
+            None => return api.personal_fm(),
+        };
+        let current = self.current_playing.as_ref().unwrap_or(&seed.track);
+        let tracks = radio_tracks(api, seed, current)?;
+        // skip tracks just played if possible
+        let queue = &self.my_playlist.tracks;
+        let fresh = tracks
+            .iter()
+            .filter(|t| !queue.contains(t))
+            .cloned()
+            .collect::<Vec<Track>>();
+        if fresh.is_empty() {
+            Ok(tracks)
+        } else {
+            Ok(fresh)
         }
     }
 
@@ -834,4 +1968,1079 @@ impl App {
         let current_route = self.get_current_route().clone();
         self.set_current_route_state(Some(ActiveBlock::Empty), Some(current_route.hovered_block));
     }
+
+    // load current user playlists
+    pub fn get_user_playlists(&mut self) {
+        if let Some(api) = &self.cloud_music {
+            match api.user_playlists(&self.user_id.to_string()) {
+                Ok(p) => {
+                    self.playlists = Some(p);
+                    self.selected_playlist_index = Some(0);
+                }
+                Err(e) => {
+                    self.handle_error(e);
+                }
+            }
+        }
+        self.retry_after_login(move |app| app.get_user_playlists());
+    }
+
+    // show login page, error shows inline
+    pub fn show_login(&mut self, error: Option<String>) {
+        if self.get_current_route().id != RouteId::Login {
+            self.push_navigation_stack(RouteId::Login, ActiveBlock::Login);
+        }
+        match error {
+            Some(error) => {
+                self.login_form.mode = LoginMode::Password;
+                self.login_form.set_error(error);
+            }
+            None => self.set_login_mode(self.login_form.mode),
+        }
+    }
+
+    pub fn set_login_mode(&mut self, mode: LoginMode) {
+        self.login_form.mode = mode;
+        self.login_form.selected_field = 0;
+        self.login_form.error = None;
+        self.login_form.info = None;
+        if mode == LoginMode::QrCode {
+            self.qr_login();
+        }
+    }
+
+    // login with cellphone/email and password
+    pub fn password_login(&mut self) {
+        let form = self.login_form.to_owned();
+        if form.account.is_empty() || form.password.is_empty() {
+            self.login_form
+                .set_error("Please input account and password".to_string());
+            return;
+        }
+        let result = match &self.cloud_music {
+            Some(api) => api.login(&form.account, &form.password),
+            None => return,
+        };
+        match result {
+            Ok(_) => self.login_success(),
+            Err(e) => self.login_form.set_error(e.to_string()),
+        }
+    }
+
+    // send sms verification code
+    pub fn send_login_captcha(&mut self) {
+        let phone = self.login_form.account.to_owned();
+        if phone.is_empty() {
+            self.login_form.set_error("Please input phone number".to_string());
+            return;
+        }
+        let result = match &self.cloud_music {
+            Some(api) => api.sms_captcha_sent(&phone),
+            None => return,
+        };
+        match result {
+            Ok(_) => {
+                self.login_form.selected_field = 1;
+                self.login_form
+                    .set_info(format!("Verification code sent to {}", phone));
+            }
+            Err(e) => self.login_form.set_error(e.to_string()),
+        }
+    }
+
+    // verify sms code then login with it
+    pub fn captcha_login(&mut self) {
+        let form = self.login_form.to_owned();
+        if form.account.is_empty() || form.captcha.is_empty() {
+            self.login_form
+                .set_error("Please input phone number and verification code".to_string());
+            return;
+        }
+        // login checks the code itself, a verify call first would use it up
+        let result = match &self.cloud_music {
+            Some(api) => api.captcha_login(&form.account, &form.captcha),
+            None => return,
+        };
+        match result {
+            Ok(_) => self.login_success(),
+            Err(e) => self.login_form.set_error(e.to_string()),
+        }
+    }
+
+    // start qrcode login, get a new key and show login page
+    pub fn qr_login(&mut self) {
+        if let Some(api) = &self.cloud_music {
+            match api.login_qr_key() {
+                Ok(unikey) => {
+                    self.qr_login = Some(QrLogin {
+                        unikey,
+                        status: "Scan with Netease Cloud Music app".to_string(),
+                        tick: 0,
+                    });
+                    if self.get_current_route().id != RouteId::Login {
+                        self.push_navigation_stack(RouteId::Login, ActiveBlock::Login);
+                    }
+                }
+                Err(e) => self.handle_error(e),
+            }
+        }
+    }
+
+    // poll qrcode login status every 8 ticks
+    fn check_qr_login(&mut self) {
+        let unikey = match &mut self.qr_login {
+            Some(qr_login) => {
+                qr_login.tick += 1;
+                if qr_login.tick % 8 != 0 {
+                    return;
+                }
+                qr_login.unikey.to_owned()
+            }
+            None => return,
+        };
+        let code = match &self.cloud_music {
+            Some(api) => api.login_qr_check(&unikey),
+            None => return,
+        };
+        let status = match code {
+            Ok(800) => "QR code expired, press r to refresh",
+            Ok(801) => "Scan with Netease Cloud Music app",
+            Ok(802) => "Scanned, please confirm on your phone",
+            Ok(803) => {
+                self.login_success();
+                return;
+            }
+            Ok(_) | Err(_) => "Check login status failed, press r to refresh",
+        };
+        if let Some(qr_login) = &mut self.qr_login {
+            qr_login.status = status.to_string();
+        }
+    }
+
+    // reload user data and leave login page after login
+    pub fn login_success(&mut self) {
+        let profile = match &self.cloud_music {
+            Some(api) => api.login_status(),
+            None => return,
+        };
+        match profile {
+            Ok(Some(profile)) => {
+                if let Some(api) = &self.cloud_music {
+                    api.reset_session_expired();
+                }
+                self.user_id = profile.userId.unwrap_or(0);
+                self.qr_login = None;
+                self.login_form = LoginForm::default();
+                self.token_refreshed_at = Instant::now();
+                self.get_user_playlists();
+                self.get_liked_ids();
+                if self.get_current_route().id == RouteId::Login {
+                    self.pop_navigation_stack();
+                }
+                match self.relogin_retry.take() {
+                    // back to where login expired and retry the failed request
+                    Some(retry) => retry(self),
+                    None => {
+                        self.msg = format!("welcome {}", profile.nickname.unwrap_or_default());
+                        self.set_current_route_state(Some(ActiveBlock::Msg), None);
+                    }
+                }
+            }
+            Ok(None) => match &mut self.qr_login {
+                Some(qr_login) if self.login_form.mode == LoginMode::QrCode => {
+                    qr_login.status = "Login failed, press r to refresh".to_string();
+                }
+                _ => self.login_form.set_error("Login failed".to_string()),
+            },
+            Err(e) => self.handle_error(e),
+        }
+    }
+
+    pub fn session_expired(&self) -> bool {
+        match &self.cloud_music {
+            Some(api) => api.session_expired(),
+            None => false,
+        }
+    }
+
+    // remember a request failed for login expired, it is retried after login again
+    // called at the end of requests, so an outer request replaces the inner ones
+    fn retry_after_login<F: FnOnce(&mut App) + 'static>(&mut self, retry: F) {
+        if self.session_expired() && self.get_current_route().id != RouteId::Login {
+            self.relogin_retry = Some(Box::new(retry));
+        }
+    }
+
+    // login expired, show login page again
+    pub fn relogin(&mut self) {
+        // drop error pages of the failed request
+        self.navigation_stack.retain(|route| route.id != RouteId::Error);
+        self.show_login(None);
+        self.login_form
+            .set_info("Login expired, please login again".to_string());
+    }
+
+    pub fn refresh_token(&mut self) {
+        self.token_refreshed_at = Instant::now();
+        if let Some(api) = &self.cloud_music {
+            if let Err(e) = api.refresh_token() {
+                error!("{}", e);
+            }
+        }
+    }
+
+    // login with cookie of profile, or account in config
+    // show login page if both failed
+    pub fn login_profile(&mut self, profile: &ProfileConfig) {
+        let api = CloudMusic::for_profile(&profile.name);
+        self.cloud_music = Some(api.to_owned());
+        self.profile = profile.name.to_owned();
+        self.user_id = 0;
+        self.liked_ids.clear();
+        self.fm = Default::default();
+        self.recent = paths::recent_file(&profile.name)
+            .map(RecentTracks::load)
+            .unwrap_or_default();
+        self.search_history = paths::search_history_file(&profile.name)
+            .map(SearchHistory::load)
+            .unwrap_or_default();
+        self.player
+            .set_cache_dir(paths::profile_dir(Dir::Cache, &profile.name));
+
+        let result = match api.login_status() {
+            Ok(Some(user)) => Ok(user),
+            Ok(None) => match (profile.username.to_owned(), profile.password.to_owned()) {
+                (Some(username), Some(password)) => api.login(&username, &password),
+                _ => {
+                    self.show_login(None);
+                    return;
+                }
+            },
+            Err(e) => {
+                self.handle_error(e);
+                return;
+            }
+        };
+        match result {
+            Ok(user) => {
+                // login status of an expired cookie has marked the session expired
+                api.reset_session_expired();
+                self.user_id = user.userId.unwrap_or(0);
+                // keep the saved session alive
+                self.refresh_token();
+                self.get_user_playlists();
+                self.get_liked_ids();
+            }
+            // login with account in config failed
+            Err(e) => {
+                self.login_form.account = profile.username.to_owned().unwrap_or_default();
+                self.show_login(Some(e.to_string()));
+            }
+        }
+    }
+
+    pub fn show_profiles(&mut self) {
+        let profiles = settings::profiles();
+        let selected_index = profiles
+            .iter()
+            .position(|profile| profile.name == self.profile)
+            .unwrap_or(0);
+        self.profile_list = Some(ProfileTable {
+            profiles,
+            selected_index,
+        });
+        if self.get_current_route().id != RouteId::Profiles {
+            self.push_navigation_stack(RouteId::Profiles, ActiveBlock::Profiles);
+        }
+    }
+
+    // switch to another account without restart
+    pub fn switch_profile(&mut self, profile: ProfileConfig) {
+        self.reset_playback();
+        self.navigation_stack = vec![DEFAULT_ROUTE];
+        self.playlists = None;
+        self.selected_playlist_index = None;
+        self.my_playlist = Default::default();
+        self.track_table = Default::default();
+        self.radio_seed = None;
+        // pages and searches of previous account
+        self.search_results = Default::default();
+        self.search_cache = Default::default();
+        self.search_suggestion = Default::default();
+        self.search_error = None;
+        self.artist_detail = None;
+        self.selected_album = None;
+        self.playlist_list = None;
+        self.album_list = None;
+        self.artist_list = None;
+        self.djradio_list = None;
+        self.program_list = None;
+        self.playlist_picker = None;
+        self.comment_list = None;
+        self.dialog = None;
+        self.user_page = None;
+        self.discover = None;
+        self.history = None;
+        self.chart_list = None;
+        self.category_picker = None;
+        self.qr_login = None;
+        self.login_form = LoginForm::default();
+        self.relogin_retry = None;
+        self.login_profile(&profile);
+        if self.user_id != 0 {
+            self.msg = format!("switch to profile {}", profile.name);
+            self.set_current_route_state(Some(ActiveBlock::Msg), None);
+        }
+    }
+
+    // stop playing and clear playing state
+    pub fn reset_playback(&mut self) {
+        self.player.reset();
+        self.current_playing = None;
+        self.duration_ms = None;
+        self.song_progress_ms = 0;
+        self.lyric = None;
+        self.lyric_index = 0;
+        self.fm_state = false;
+    }
+
+    // playlists created by current user
+    pub fn own_playlists(&self) -> Vec<Playlist> {
+        match &self.playlists {
+            Some(playlists) => playlists
+                .iter()
+                .filter(|p| p.userId == Some(self.user_id as i64))
+                .cloned()
+                .collect(),
+            None => vec![],
+        }
+    }
+
+    // choose a playlist to add track
+    // selected track of the track list in current block
+    fn selected_track(&self) -> Option<Track> {
+        match self.get_current_route().active_block {
+            ActiveBlock::TrackTable => self
+                .track_table
+                .tracks
+                .get(self.track_table.selected_index)
+                .cloned(),
+            ActiveBlock::AlbumTracks => self
+                .selected_album
+                .as_ref()
+                .and_then(|album| album.tracks.get(album.selected_index))
+                .cloned(),
+            ActiveBlock::SearchResult => match self.tabs.index {
+                0 => self
+                    .search_results
+                    .tracks
+                    .as_ref()
+                    .and_then(|l| l.selected())
+                    .cloned(),
+                5 => self
+                    .search_results
+                    .lyrics
+                    .as_ref()
+                    .and_then(|l| l.selected())
+                    .map(|l| l.track.to_owned()),
+                _ => None,
+            },
+            ActiveBlock::Artist => self
+                .artist_detail
+                .as_ref()
+                .filter(|artist| artist.tab == ArtistTab::Songs)
+                .and_then(|artist| artist.songs.get(artist.selected_song_index))
+                .cloned(),
+            ActiveBlock::Discover => self
+                .discover
+                .as_ref()
+                .filter(|discover| discover.section == DiscoverSection::Songs)
+                .and_then(|discover| discover.songs.get(discover.selected_song_index))
+                .cloned(),
+            ActiveBlock::History => self
+                .history
+                .as_ref()
+                .and_then(|history| history.tracks.get(history.selected_index))
+                .cloned(),
+            _ => None,
+        }
+    }
+
+    // choose playlist for selected track of any track list
+    pub fn add_selected_to_playlist(&mut self) {
+        if let Some(track) = self.selected_track() {
+            self.show_playlist_picker(track);
+        }
+    }
+
+    pub fn show_playlist_picker(&mut self, track: Track) {
+        let playlists = self.own_playlists();
+        if playlists.is_empty() {
+            self.msg = "no playlist created by you".to_string();
+            self.set_current_route_state(Some(ActiveBlock::Msg), None);
+            return;
+        }
+        self.playlist_picker = Some(PlaylistPicker {
+            track,
+            playlists,
+            selected_index: 0,
+        });
+        self.push_navigation_stack(RouteId::PlaylistPicker, ActiveBlock::PlaylistPicker);
+    }
+
+    // add track to selected playlist of picker
+    pub fn add_track_to_playlist(&mut self) {
+        let picker = match self.playlist_picker.take() {
+            Some(picker) => picker,
+            None => return,
+        };
+        // leave picker page first, it can not be drawn without picker
+        self.pop_navigation_stack();
+        let playlist = match picker.playlists.get(picker.selected_index) {
+            Some(playlist) => playlist.to_owned(),
+            None => return,
+        };
+        let (playlist_id, track_id) = match (playlist.id, picker.track.id) {
+            (Some(playlist_id), Some(track_id)) => (playlist_id, track_id),
+            _ => return,
+        };
+        let result = match &self.cloud_music {
+            Some(api) => api.playlist_tracks(&playlist_id.to_string(), &[track_id], true),
+            None => return,
+        };
+        match result {
+            Ok(_) => {
+                // show new track if the playlist is opened
+                if self.track_table.playlist_id == Some(playlist_id) {
+                    self.track_table.tracks.insert(0, picker.track.to_owned());
+                }
+                self.msg = format!(
+                    "add {} to {}",
+                    picker.track.name.unwrap_or_default(),
+                    playlist.name.unwrap_or_default()
+                );
+                self.set_current_route_state(Some(ActiveBlock::Msg), None);
+            }
+            Err(e) => self.handle_error(e),
+        }
+    }
+
+    // remove selected track from opened playlist
+    pub fn remove_track_from_playlist(&mut self) {
+        let playlist_id = match self.track_table.playlist_id {
+            Some(playlist_id) => playlist_id,
+            None => return,
+        };
+        if !self
+            .own_playlists()
+            .iter()
+            .any(|p| p.id == Some(playlist_id))
+        {
+            self.msg = "only remove track from your own playlist".to_string();
+            self.set_current_route_state(Some(ActiveBlock::Msg), None);
+            return;
+        }
+        let index = self.track_table.selected_index;
+        let track = match self.track_table.tracks.get(index) {
+            Some(track) => track.to_owned(),
+            None => return,
+        };
+        let result = match (&self.cloud_music, track.id) {
+            (Some(api), Some(track_id)) => {
+                api.playlist_tracks(&playlist_id.to_string(), &[track_id], false)
+            }
+            _ => return,
+        };
+        match result {
+            Ok(_) => {
+                self.track_table.tracks.remove(index);
+                if index > 0 && index >= self.track_table.tracks.len() {
+                    self.track_table.selected_index = index - 1;
+                }
+                self.msg = format!("remove {}", track.name.unwrap_or_default());
+                self.set_current_route_state(Some(ActiveBlock::Msg), None);
+            }
+            Err(e) => self.handle_error(e),
+        }
+    }
+
+    // search current tab only, other tabs are fetched on first visit
+    pub fn search(&mut self, query: String) {
+        // show parse error under input instead of searching
+        let filter = match SearchQuery::parse(&query) {
+            Ok(filter) => filter,
+            Err(e) => {
+                self.search_error = Some(e);
+                return;
+            }
+        };
+        self.search_error = None;
+        self.search_history.push(&query);
+        if let Err(e) = self.search_history.save() {
+            error!("save search history failed: {}", e);
+        }
+        let results = mem::take(&mut self.search_results);
+        if !results.query.is_empty() {
+            self.search_cache.retain(|r| r.query != results.query);
+            self.search_cache.push(results);
+            if self.search_cache.len() > SEARCH_CACHE_SIZE {
+                self.search_cache.remove(0);
+            }
+        }
+        // qualifiers choose the tab
+        if let Some(tab) = filter.tab() {
+            self.tabs.index = tab;
+        }
+        // reuse results of recent query
+        self.search_results = match self.search_cache.iter().position(|r| r.query == query) {
+            Some(index) => self.search_cache.remove(index),
+            None => SearchResult::new(query, filter),
+        };
+        // open results first, so error page of loading shows above them
+        self.selected_playlist_index = None;
+        self.push_navigation_stack(RouteId::Search, ActiveBlock::SearchResult);
+        self.search_current_tab();
+    }
+
+    // empty input shows history and hot searches
+    // otherwise suggestions are fetched when typing pauses
+    pub fn search_input_changed(&mut self) {
+        self.search_error = None;
+        self.search_suggestion.selected_index = None;
+        if !self.input.is_empty() {
+            self.search_suggestion.changed_at = Some(Instant::now());
+            return;
+        }
+        self.search_suggestion.changed_at = None;
+        if self.search_suggestion.hots.is_none() {
+            if let Some(api) = &self.cloud_music {
+                let hots = api.search_hot().unwrap_or_else(|e| {
+                    error!("get hot searches failed: {}", e);
+                    vec![]
+                });
+                self.search_suggestion.hots = Some(hots);
+            }
+        }
+        let history = self.search_history.queries.iter().map(|q| ("History", q.to_owned()));
+        let hots = self.search_suggestion.hots.iter().flatten().map(|q| ("Hot", q.to_owned()));
+        self.search_suggestion.items = history.chain(hots).collect();
+    }
+
+    fn update_search_suggestion(&mut self) {
+        match self.search_suggestion.changed_at {
+            Some(changed_at) if changed_at.elapsed() >= SUGGEST_DELAY => {}
+            _ => return,
+        }
+        self.search_suggestion.changed_at = None;
+        let input: String = self.input.iter().collect();
+        if let Some(api) = &self.cloud_music {
+            match api.search_suggest(&input) {
+                Ok(suggest) => self.search_suggestion.items = suggestion_items(suggest),
+                // no suggestions is fine, keep typing
+                Err(e) => {
+                    error!("get search suggestions failed: {}", e);
+                    self.search_suggestion.items = vec![];
+                }
+            }
+        }
+    }
+
+    // move in dropdown, moving up from first item back to input
+    pub fn select_suggestion(&mut self, down: bool) {
+        let suggestion = &mut self.search_suggestion;
+        let len = suggestion.items.len();
+        if len == 0 {
+            return;
+        }
+        suggestion.selected_index = match (suggestion.selected_index, down) {
+            (None, true) => Some(0),
+            (None, false) => Some(len - 1),
+            (Some(index), true) => Some((index + 1) % len),
+            (Some(0), false) => None,
+            (Some(index), false) => Some(index - 1),
+        };
+    }
+
+    pub fn selected_suggestion(&self) -> Option<String> {
+        let suggestion = &self.search_suggestion;
+        suggestion
+            .selected_index
+            .and_then(|index| suggestion.items.get(index))
+            .map(|(_, keyword)| keyword.to_owned())
+    }
+
+    // fetch first page of current tab if not fetched yet
+    pub fn search_current_tab(&mut self) {
+        if self.search_results.counts(self.tabs.index).is_none() {
+            self.load_search_page(self.tabs.index);
+        }
+    }
+
+    // next page of current tab
+    pub fn search_more(&mut self) {
+        self.load_search_page(self.tabs.index);
+    }
+
+    fn load_search_page(&mut self, tab: usize) {
+        let offset = match self.search_results.next_offset(tab) {
+            Some(offset) => offset,
+            None => return,
+        };
+        let api = match &self.cloud_music {
+            Some(api) => api,
+            None => return,
+        };
+        let results = &mut self.search_results;
+        let filter = results.filter.to_owned();
+        let keyword = filter.search_keyword();
+        let limit = SEARCH_PAGE_SIZE;
+        let result = match tab {
+            0 => api.search_track(&keyword, limit, offset).map(|r| {
+                let tracks = r.songs.unwrap_or_default();
+                append_page(&mut results.tracks, tracks, |t| filter.matches_track(t), r.songCount)
+            }),
+            1 => api.search_artist(&keyword, limit, offset).map(|r| {
+                let artists = r.artists.unwrap_or_default();
+                let keep = |a: &Artist| filter.matches_artist(a);
+                append_page(&mut results.artists, artists, keep, r.artistCount)
+            }),
+            2 => api.search_album(&keyword, limit, offset).map(|r| {
+                let albums = r.albums.unwrap_or_default();
+                let keep = |a: &Album| filter.matches_album(a);
+                append_page(&mut results.albums, albums, keep, r.albumCount)
+            }),
+            3 => api.search_playlist(&keyword, limit, offset).map(|r| {
+                let playlists = r.playlists.unwrap_or_default();
+                append_page(&mut results.playlists, playlists, |_| true, r.playlistCount)
+            }),
+            4 => api.search_djradio(&keyword, limit, offset).map(|r| {
+                let djradios = r.djRadios.unwrap_or_default();
+                append_page(&mut results.djradios, djradios, |_| true, r.djRadiosCount)
+            }),
+            5 => api.search_lyric(&keyword, limit, offset).map(|r| {
+                let lyrics = r.songs.unwrap_or_default();
+                let keep = |l: &LyricTrack| filter.matches_track(&l.track);
+                append_page(&mut results.lyrics, lyrics, keep, r.songCount)
+            }),
+            6 => api.search_user(&keyword, limit, offset).map(|r| {
+                let users = r.userprofiles.unwrap_or_default();
+                append_page(&mut results.users, users, |_| true, r.userprofileCount)
+            }),
+            _ => Ok(()),
+        };
+        if let Err(e) = result {
+            self.handle_error(e);
+        }
+        self.retry_after_login(move |app| app.load_search_page(tab));
+    }
+
+    // show hot comments first
+    pub fn show_comments(&mut self, kind: CommentType, id: i64, name: String) {
+        self.comment_list = Some(CommentTable {
+            thread_id: kind.thread_id(id),
+            title: name,
+            hot: true,
+            comments: vec![],
+            total: 0,
+            selected_index: 0,
+            selected_page: 0,
+        });
+        self.get_comments(0);
+        if self.get_current_route().id != RouteId::Comments {
+            self.push_navigation_stack(RouteId::Comments, ActiveBlock::Comments);
+        }
+    }
+
+    pub fn get_comments(&mut self, page: usize) {
+        let (thread_id, hot) = match &self.comment_list {
+            Some(list) => (list.thread_id.to_owned(), list.hot),
+            None => return,
+        };
+        let result = match &self.cloud_music {
+            Some(api) => api.comments(
+                &thread_id,
+                hot,
+                COMMENT_PAGE_SIZE,
+                page as i32 * COMMENT_PAGE_SIZE,
+            ),
+            None => return,
+        };
+        match result {
+            Ok((comments, total)) => {
+                if let Some(list) = &mut self.comment_list {
+                    // no more comments, stay at current page
+                    if comments.is_empty() && page > list.selected_page {
+                        return;
+                    }
+                    list.comments = comments;
+                    list.total = total;
+                    list.selected_index = 0;
+                    list.selected_page = page;
+                }
+            }
+            Err(e) => self.handle_error(e),
+        }
+        self.retry_after_login(move |app| app.get_comments(page));
+    }
+
+    // switch hot and latest comments
+    pub fn switch_comment_tab(&mut self) {
+        if let Some(list) = &mut self.comment_list {
+            list.hot = !list.hot;
+            self.get_comments(0);
+        }
+    }
+
+    // like or unlike selected comment
+    pub fn like_comment(&mut self) {
+        let (thread_id, comment) = match &self.comment_list {
+            Some(list) => match list.comments.get(list.selected_index) {
+                Some(comment) => (list.thread_id.to_owned(), comment.to_owned()),
+                None => return,
+            },
+            None => return,
+        };
+        let like = !comment.liked.unwrap_or(false);
+        let result = match &self.cloud_music {
+            Some(api) => api.like_comment(&thread_id, comment.commentId, like),
+            None => return,
+        };
+        match result {
+            Ok(_) => {
+                if let Some(list) = &mut self.comment_list {
+                    if let Some(comment) = list.comments.get_mut(list.selected_index) {
+                        let count = comment.likedCount.unwrap_or(0);
+                        comment.liked = Some(like);
+                        comment.likedCount = Some(if like { count + 1 } else { count - 1 });
+                    }
+                }
+            }
+            Err(e) => self.handle_error(e),
+        }
+    }
+
+    // write comment, or reply to selected comment
+    pub fn show_comment_dialog(&mut self, reply: bool) {
+        let (thread_id, reply_to) = match &self.comment_list {
+            Some(list) => {
+                let reply_to = list
+                    .comments
+                    .get(list.selected_index)
+                    .filter(|_| reply)
+                    .map(|c| (c.commentId, c.user.nickname.to_owned().unwrap_or_default()));
+                (list.thread_id.to_owned(), reply_to)
+            }
+            None => return,
+        };
+        let title = match &reply_to {
+            Some((_, nickname)) => format!("Reply to {}", nickname),
+            None => "Write comment".to_string(),
+        };
+        self.open_dialog(
+            title,
+            DialogAction::Comment {
+                thread_id,
+                reply_to: reply_to.map(|(id, _)| id),
+            },
+        );
+    }
+
+    pub fn open_dialog(&mut self, title: String, action: DialogAction) {
+        self.dialog = Some(Dialog {
+            title,
+            input: String::new(),
+            action,
+        });
+        self.set_current_route_state(Some(ActiveBlock::Dialog), None);
+    }
+
+    // back to the block opened the dialog
+    pub fn close_dialog(&mut self) {
+        self.dialog = None;
+        let hovered_block = self.get_current_route().hovered_block;
+        self.set_current_route_state(Some(hovered_block), None);
+    }
+
+    pub fn submit_dialog(&mut self) {
+        let dialog = match &self.dialog {
+            Some(dialog) if !dialog.input.trim().is_empty() => dialog.to_owned(),
+            _ => return,
+        };
+        self.close_dialog();
+        let input = dialog.input.trim().to_owned();
+        match dialog.action {
+            DialogAction::Comment {
+                thread_id,
+                reply_to,
+            } => {
+                let result = match &self.cloud_music {
+                    Some(api) => api.post_comment(&thread_id, &input, reply_to),
+                    None => return,
+                };
+                match result {
+                    Ok(_) => {
+                        // new comment shows in latest comments
+                        if let Some(list) = &mut self.comment_list {
+                            list.hot = false;
+                        }
+                        self.get_comments(0);
+                        self.msg = "comment sent".to_string();
+                        self.set_current_route_state(Some(ActiveBlock::Msg), None);
+                    }
+                    Err(e) => self.handle_error(e),
+                }
+            }
+            DialogAction::CreatePlaylist { private } => {
+                let result = match &self.cloud_music {
+                    Some(api) => api.create_playlist(&input, private),
+                    None => return,
+                };
+                self.playlist_changed(result.map(|_| format!("create playlist {}", input)));
+            }
+            DialogAction::RenamePlaylist { playlist_id } => {
+                let result = match &self.cloud_music {
+                    Some(api) => api.rename_playlist(&playlist_id.to_string(), &input),
+                    None => return,
+                };
+                self.playlist_changed(result.map(|_| format!("rename playlist to {}", input)));
+            }
+            DialogAction::DeletePlaylist { playlist_id } => {
+                if input != "yes" {
+                    return;
+                }
+                let result = match &self.cloud_music {
+                    Some(api) => api.delete_playlist(&playlist_id.to_string()),
+                    None => return,
+                };
+                self.playlist_changed(result.map(|_| "playlist deleted".to_string()));
+            }
+        }
+    }
+
+    // selected playlist of my playlists, only own one can be changed
+    fn selected_own_playlist(&mut self) -> Option<(i64, String)> {
+        let playlist = match (&self.playlists, self.selected_playlist_index) {
+            (Some(playlists), Some(index)) => playlists.get(index).cloned(),
+            _ => None,
+        }?;
+        match playlist.id {
+            Some(playlist_id) if playlist.userId == Some(self.user_id as i64) => {
+                Some((playlist_id, playlist.name.unwrap_or_default()))
+            }
+            _ => {
+                self.msg = "only playlist created by you can be changed".to_string();
+                self.set_current_route_state(Some(ActiveBlock::Msg), None);
+                None
+            }
+        }
+    }
+
+    pub fn open_create_playlist(&mut self, private: bool) {
+        let title = if private {
+            "New private playlist"
+        } else {
+            "New playlist"
+        };
+        self.open_dialog(title.to_string(), DialogAction::CreatePlaylist { private });
+    }
+
+    pub fn open_rename_playlist(&mut self) {
+        if let Some((playlist_id, name)) = self.selected_own_playlist() {
+            self.open_dialog(
+                format!("Rename {}", name),
+                DialogAction::RenamePlaylist { playlist_id },
+            );
+        }
+    }
+
+    pub fn open_delete_playlist(&mut self) {
+        if let Some((playlist_id, name)) = self.selected_own_playlist() {
+            self.open_dialog(
+                format!("Delete {}? Type yes to confirm", name),
+                DialogAction::DeletePlaylist { playlist_id },
+            );
+        }
+    }
+
+    // reload my playlists after created, renamed or deleted one
+    fn playlist_changed(&mut self, result: Result<String, failure::Error>) {
+        match result {
+            Ok(msg) => {
+                self.get_user_playlists();
+                self.msg = msg;
+                self.set_current_route_state(Some(ActiveBlock::Msg), None);
+            }
+            Err(e) => self.handle_error(e),
+        }
+    }
+
+    // show user detail and created/subscribed playlists
+    pub fn show_user(&mut self, user_id: i64) {
+        let result = match &self.cloud_music {
+            Some(api) => api.user(&user_id.to_string()).and_then(|user| {
+                api.uid_playlists(&user_id.to_string())
+                    .map(|playlists| (user, playlists))
+            }),
+            None => return,
+        };
+        match result {
+            Ok((user, playlists)) => {
+                self.user_page = Some(UserPage {
+                    user_id,
+                    user,
+                    playlists,
+                    selected_index: 0,
+                });
+                if self.get_current_route().id != RouteId::User {
+                    self.push_navigation_stack(RouteId::User, ActiveBlock::User);
+                }
+            }
+            Err(e) => self.handle_error(e),
+        }
+        self.retry_after_login(move |app| app.show_user(user_id));
+    }
+
+    // personalized discover page
+    pub fn show_discover(&mut self) {
+        let api = match &self.cloud_music {
+            Some(api) => api,
+            None => return,
+        };
+        // load sections apart, a failed one is left empty
+        let playlists = api.personalized_playlists(30).unwrap_or_else(|e| {
+            error!("get personalized playlists failed: {}", e);
+            Vec::new()
+        });
+        let songs = api.personalized_new_songs().unwrap_or_else(|e| {
+            error!("get personalized new songs failed: {}", e);
+            Vec::new()
+        });
+        let programs = api.personalized_programs().unwrap_or_else(|e| {
+            error!("get personalized programs failed: {}", e);
+            Vec::new()
+        });
+        self.discover = Some(Discover {
+            playlists,
+            songs,
+            programs,
+            ..Default::default()
+        });
+        self.push_navigation_stack(RouteId::Discover, ActiveBlock::Discover);
+        self.retry_after_login(move |app| app.show_discover());
+    }
+
+    pub fn show_charts(&mut self) {
+        let result = match &self.cloud_music {
+            Some(api) => api.toplists(),
+            None => return,
+        };
+        match result {
+            Ok(charts) => {
+                self.chart_list = Some(ChartTable {
+                    charts,
+                    selected_index: 0,
+                });
+                self.push_navigation_stack(RouteId::Charts, ActiveBlock::Charts);
+            }
+            Err(e) => self.handle_error(e),
+        }
+        self.retry_after_login(move |app| app.show_charts());
+    }
+
+    pub fn show_history(&mut self) {
+        self.history = Some(History::default());
+        self.load_history(HistoryTab::Recent);
+        if self.get_current_route().id != RouteId::History {
+            self.push_navigation_stack(RouteId::History, ActiveBlock::History);
+        }
+    }
+
+    pub fn switch_history_tab(&mut self) {
+        if let Some(history) = &self.history {
+            let tab = history.tab.next();
+            self.load_history(tab);
+        }
+    }
+
+    // recent tracks from local, ranking from api
+    fn load_history(&mut self, tab: HistoryTab) {
+        let result = match tab {
+            HistoryTab::Recent => Ok(self
+                .recent
+                .tracks
+                .iter()
+                .map(|t| (t.to_owned(), 0))
+                .collect()),
+            HistoryTab::Week | HistoryTab::All => match &self.cloud_music {
+                Some(api) => api
+                    .play_record(&self.user_id.to_string(), tab == HistoryTab::Week)
+                    .map(|records| {
+                        records
+                            .into_iter()
+                            .map(|r| (Track::from(r.song), r.playCount.unwrap_or(0)))
+                            .collect::<Vec<(Track, i32)>>()
+                    }),
+                None => return,
+            },
+        };
+        match result {
+            Ok(records) => {
+                let (tracks, play_counts) = records.into_iter().unzip();
+                self.history = Some(History {
+                    tab,
+                    tracks,
+                    play_counts: if tab == HistoryTab::Recent {
+                        vec![]
+                    } else {
+                        play_counts
+                    },
+                    selected_index: 0,
+                });
+            }
+            Err(e) => self.handle_error(e),
+        }
+        self.retry_after_login(move |app| app.load_history(tab));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_artist_filter_initial() {
+        let filter = ArtistFilter::default();
+        assert_eq!(filter.next_initial(true).initial, Some('A'));
+        assert_eq!(filter.next_initial(false).initial, Some('Z'));
+        let filter = filter.next_initial(false);
+        assert_eq!(filter.next_initial(true).initial, None);
+        assert_eq!(filter.next_initial(false).initial, Some('Y'));
+        assert_eq!(filter.initial_code(), 'Z' as i32);
+        assert!(!filter.is_default());
+        assert!(filter.next_initial(true).is_default());
+    }
+
+    #[test]
+    fn test_search_pages() {
+        let all = |_: &i32| true;
+        let mut list: Option<SearchList<i32>> = None;
+        assert_eq!(next_offset(&list), Some(0));
+        append_page(&mut list, vec![1, 2], all, Some(3));
+        assert_eq!(next_offset(&list), Some(2));
+        append_page(&mut list, vec![3], all, None);
+        assert_eq!(next_offset(&list), None);
+
+        // empty page stops loading even if total says more
+        let mut list = None;
+        append_page(&mut list, vec![1], all, Some(10));
+        append_page(&mut list, vec![], all, Some(10));
+        assert_eq!(list.as_ref().map(|l| l.total), Some(1));
+        assert_eq!(next_offset(&list), None);
+
+        // filtered items still count for offset
+        let mut list = None;
+        append_page(&mut list, vec![1, 2, 3], |n| n % 2 == 1, Some(10));
+        assert_eq!(list.as_ref().map(|l| l.items.to_owned()), Some(vec![1, 3]));
+        assert_eq!(next_offset(&list), Some(3));
+    }
 }
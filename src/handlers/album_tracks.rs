@@ -1,4 +1,5 @@
 use super::super::app::{App, TrackTable};
+use super::super::model::comment::CommentType;
 use super::common_events;
 use termion::event::Key;
 
@@ -29,6 +30,7 @@ pub fn handler(key: Key, app: &mut App) {
                     tracks: selected_album.tracks.to_owned(),
                     selected_index: selected_album.selected_index,
                     name: selected_album.album.name.to_owned().unwrap(),
+                    playlist_id: None,
                 };
                 if let Some(selected_track) = selected_album
                     .tracks
@@ -40,6 +42,42 @@ pub fn handler(key: Key, app: &mut App) {
                 }
             };
         }
+        // add track to playlist
+        Key::Alt('a') => app.add_selected_to_playlist(),
+        // song radio from selected track
+        Key::Alt('r') => {
+            let track = match &app.selected_album {
+                Some(selected_album) => selected_album
+                    .tracks
+                    .get(selected_album.selected_index)
+                    .cloned(),
+                None => None,
+            };
+            if let Some(track) = track {
+                app.start_radio(track, None);
+            }
+        }
+        Key::Char('c') => {
+            let track = match &app.selected_album {
+                Some(selected_album) => selected_album
+                    .tracks
+                    .get(selected_album.selected_index)
+                    .cloned(),
+                None => None,
+            };
+            if let Some(track) = track {
+                let name = track.name.unwrap_or_default();
+                app.show_comments(CommentType::Song, track.id.unwrap_or(0), name);
+            }
+        }
+        // comments of album
+        Key::Char('C') => {
+            if let Some(selected_album) = &app.selected_album {
+                let album = selected_album.album.to_owned();
+                let name = album.name.unwrap_or_default();
+                app.show_comments(CommentType::Album, album.id.unwrap_or(0) as i64, name);
+            }
+        }
         _ => {}
     }
 }
@@ -1,4 +1,5 @@
 use super::super::app::App;
+use super::super::model::comment::CommentType;
 use super::common_events;
 use termion::event::Key;
 
@@ -31,8 +32,20 @@ pub fn handler(key: Key, app: &mut App) {
                 }
             };
         }
+        Key::Char('c') => {
+            let album = match &app.album_list {
+                Some(albumlist) => albumlist.albums.get(albumlist.selected_index).cloned(),
+                None => None,
+            };
+            if let Some(album) = album {
+                let name = album.name.unwrap_or_default();
+                app.show_comments(CommentType::Album, album.id.unwrap_or(0) as i64, name);
+            }
+        }
+        // switch area
+        Key::Char('\t') => app.switch_album_area(),
         Key::Ctrl('f') => {
-            let limit = (app.block_height - 4) as i32;
+            let limit = app.album_page_size() as i32;
             if let Some(albumlist) = &app.album_list {
                 let page = albumlist.selected_page;
                 let next_page = (page + 1) as i32;
@@ -40,7 +53,7 @@ pub fn handler(key: Key, app: &mut App) {
             };
         }
         Key::Ctrl('b') => {
-            let limit = (app.block_height - 4) as i32;
+            let limit = app.album_page_size() as i32;
             if let Some(albumlist) = &app.album_list {
                 let page = albumlist.selected_page;
                 let next_page = if page < 1 { 0 } else { page - 1 } as i32;
@@ -1,4 +1,5 @@
-use super::super::app::App;
+use super::super::app::{Action, App, ArtistTab, TrackTable};
+use super::super::model::comment::CommentType;
 use super::common_events;
 use termion::event::Key;
 
@@ -6,34 +7,131 @@ pub fn handler(key: Key, app: &mut App) {
     match key {
         k if common_events::left_event(k) => common_events::handle_left_event(app),
         k if common_events::down_event(k) => {
-            if let Some(artist_albums) = &mut app.artist_albums {
-                let next_index = common_events::on_down_press_handler(
-                    &artist_albums.albums,
-                    Some(artist_albums.selected_index),
-                );
-                artist_albums.selected_index = next_index;
+            if let Some(artist_detail) = &mut app.artist_detail {
+                match artist_detail.tab {
+                    ArtistTab::Songs => {
+                        artist_detail.selected_song_index = common_events::on_down_press_handler(
+                            &artist_detail.songs,
+                            Some(artist_detail.selected_song_index),
+                        );
+                    }
+                    ArtistTab::Albums => {
+                        artist_detail.selected_album_index = common_events::on_down_press_handler(
+                            &artist_detail.albums,
+                            Some(artist_detail.selected_album_index),
+                        );
+                    }
+                    ArtistTab::Similar => {
+                        artist_detail.selected_similar_index =
+                            common_events::on_down_press_handler(
+                                &artist_detail.similar,
+                                Some(artist_detail.selected_similar_index),
+                            );
+                    }
+                }
             }
         }
         k if common_events::up_event(k) => {
-            if let Some(artist_albums) = &mut app.artist_albums {
-                let next_index = common_events::on_up_press_handler(
-                    &artist_albums.albums,
-                    Some(artist_albums.selected_index),
-                );
-                artist_albums.selected_index = next_index;
+            if let Some(artist_detail) = &mut app.artist_detail {
+                match artist_detail.tab {
+                    ArtistTab::Songs => {
+                        artist_detail.selected_song_index = common_events::on_up_press_handler(
+                            &artist_detail.songs,
+                            Some(artist_detail.selected_song_index),
+                        );
+                    }
+                    ArtistTab::Albums => {
+                        artist_detail.selected_album_index = common_events::on_up_press_handler(
+                            &artist_detail.albums,
+                            Some(artist_detail.selected_album_index),
+                        );
+                    }
+                    ArtistTab::Similar => {
+                        artist_detail.selected_similar_index = common_events::on_up_press_handler(
+                            &artist_detail.similar,
+                            Some(artist_detail.selected_similar_index),
+                        );
+                    }
+                }
+            }
+        }
+        // switch top songs, albums and similar artists
+        Key::Char('\t') => {
+            if let Some(artist_detail) = &mut app.artist_detail {
+                artist_detail.tab = artist_detail.tab.next();
             }
         }
         Key::Char('\n') => {
-            if let Some(artist_albums) = &mut app.artist_albums {
-                if let Some(selected_album) = artist_albums
-                    .albums
-                    .get(artist_albums.selected_index)
-                    .cloned()
-                {
-                    app.get_album_tracks(selected_album.id.unwrap().to_string());
+            let artist_detail = match &app.artist_detail {
+                Some(artist_detail) => artist_detail.clone(),
+                None => return,
+            };
+            match artist_detail.tab {
+                // play top songs as queue
+                ArtistTab::Songs => {
+                    if let Some(track) = artist_detail
+                        .songs
+                        .get(artist_detail.selected_song_index)
+                    {
+                        app.my_playlist = TrackTable {
+                            tracks: artist_detail.songs.to_owned(),
+                            selected_index: artist_detail.selected_song_index,
+                            name: artist_detail.artist.name.to_owned(),
+                            playlist_id: None,
+                        };
+                        app.start_playback(track.to_owned());
+                        app.fm_state = false;
+                    }
+                }
+                ArtistTab::Albums => {
+                    let album = artist_detail
+                        .albums
+                        .get(artist_detail.selected_album_index);
+                    if let Some(album_id) = album.and_then(|a| a.id) {
+                        app.get_album_tracks(album_id.to_string());
+                    }
                 }
+                ArtistTab::Similar => {
+                    if let Some(artist) = artist_detail
+                        .similar
+                        .get(artist_detail.selected_similar_index)
+                    {
+                        app.show_artist(artist.id.to_string());
+                    }
+                }
+            }
+        }
+        Key::Char('c') => {
+            let artist_detail = match &app.artist_detail {
+                Some(artist_detail) => artist_detail.clone(),
+                None => return,
             };
+            match artist_detail.tab {
+                ArtistTab::Songs => {
+                    if let Some(track) = artist_detail
+                        .songs
+                        .get(artist_detail.selected_song_index)
+                    {
+                        let name = track.name.to_owned().unwrap_or_default();
+                        app.show_comments(CommentType::Song, track.id.unwrap_or(0), name);
+                    }
+                }
+                ArtistTab::Albums => {
+                    if let Some(album) = artist_detail
+                        .albums
+                        .get(artist_detail.selected_album_index)
+                    {
+                        let name = album.name.to_owned().unwrap_or_default();
+                        app.show_comments(CommentType::Album, album.id.unwrap_or(0) as i64, name);
+                    }
+                }
+                ArtistTab::Similar => {}
+            }
         }
+        // add top song to playlist
+        Key::Alt('a') => app.add_selected_to_playlist(),
+        Key::Alt('s') => app.follow_artist(Action::Subscribe),
+        Key::Alt('d') => app.follow_artist(Action::Unsubscribe),
         _ => {}
     }
 }
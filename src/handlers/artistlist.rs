@@ -1,4 +1,4 @@
-use super::super::app::App;
+use super::super::app::{App, ArtistFilter};
 use super::common_events;
 use termion::event::Key;
 
@@ -27,10 +27,15 @@ pub fn handler(key: Key, app: &mut App) {
             if let Some(artistlist) = &app.artist_list {
                 if let Some(artist) = artistlist.artists.get(artistlist.selected_index.to_owned()) {
                     let artist_id = artist.id;
-                    app.get_artist_albums(artist_id.to_string());
+                    app.show_artist(artist_id.to_string());
                 }
             };
         }
+        // filter by area, type and initial letter
+        Key::Char('e') => app.filter_artists(ArtistFilter::next_area),
+        Key::Char('t') => app.filter_artists(ArtistFilter::next_kind),
+        Key::Char(']') => app.filter_artists(|filter| filter.next_initial(true)),
+        Key::Char('[') => app.filter_artists(|filter| filter.next_initial(false)),
         Key::Ctrl('f') => {
             let limit = (app.block_height - 4) as i32;
             if let Some(artistlist) = &app.artist_list {
@@ -0,0 +1,49 @@
+use super::super::app::App;
+use super::super::model::comment::CommentType;
+use super::common_events;
+use termion::event::Key;
+
+pub fn handler(key: Key, app: &mut App) {
+    match key {
+        k if common_events::left_event(k) => common_events::handle_left_event(app),
+        k if common_events::down_event(k) => {
+            if let Some(chart_list) = &mut app.chart_list {
+                let next_index = common_events::on_down_press_handler(
+                    &chart_list.charts,
+                    Some(chart_list.selected_index),
+                );
+                chart_list.selected_index = next_index;
+            }
+        }
+        k if common_events::up_event(k) => {
+            if let Some(chart_list) = &mut app.chart_list {
+                let next_index = common_events::on_up_press_handler(
+                    &chart_list.charts,
+                    Some(chart_list.selected_index),
+                );
+                chart_list.selected_index = next_index;
+            }
+        }
+        // chart is a playlist
+        Key::Char('\n') => {
+            let chart = match &app.chart_list {
+                Some(chart_list) => chart_list.charts.get(chart_list.selected_index).cloned(),
+                None => None,
+            };
+            if let Some(chart_id) = chart.and_then(|c| c.id) {
+                app.get_playlist_tracks(chart_id.to_string());
+            }
+        }
+        Key::Char('c') => {
+            let chart = match &app.chart_list {
+                Some(chart_list) => chart_list.charts.get(chart_list.selected_index).cloned(),
+                None => None,
+            };
+            if let Some(chart) = chart {
+                let name = chart.name.unwrap_or_default();
+                app.show_comments(CommentType::Playlist, chart.id.unwrap_or(0), name);
+            }
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,66 @@
+use super::super::app::App;
+use super::common_events;
+use termion::event::Key;
+
+pub fn handler(key: Key, app: &mut App) {
+    match key {
+        k if common_events::left_event(k) => common_events::handle_left_event(app),
+        k if common_events::down_event(k) => {
+            if let Some(list) = &mut app.comment_list {
+                let next_index =
+                    common_events::on_down_press_handler(&list.comments, Some(list.selected_index));
+                list.selected_index = next_index;
+            }
+        }
+        k if common_events::up_event(k) => {
+            if let Some(list) = &mut app.comment_list {
+                let next_index =
+                    common_events::on_up_press_handler(&list.comments, Some(list.selected_index));
+                list.selected_index = next_index;
+            }
+        }
+        // switch hot and latest
+        Key::Char('\t') => {
+            app.switch_comment_tab();
+        }
+        Key::Ctrl('f') => {
+            if let Some(list) = &app.comment_list {
+                let next_page = list.selected_page + 1;
+                app.get_comments(next_page);
+            }
+        }
+        Key::Ctrl('b') => {
+            if let Some(list) = &app.comment_list {
+                let next_page = if list.selected_page < 1 {
+                    0
+                } else {
+                    list.selected_page - 1
+                };
+                app.get_comments(next_page);
+            }
+        }
+        Key::Char('y') => {
+            app.like_comment();
+        }
+        Key::Char('w') => {
+            app.show_comment_dialog(false);
+        }
+        Key::Char('\n') => {
+            app.show_comment_dialog(true);
+        }
+        // author of comment
+        Key::Char('u') => {
+            let user_id = match &app.comment_list {
+                Some(list) => list
+                    .comments
+                    .get(list.selected_index)
+                    .and_then(|c| c.user.userId),
+                None => None,
+            };
+            if let Some(user_id) = user_id {
+                app.show_user(user_id);
+            }
+        }
+        _ => {}
+    }
+}
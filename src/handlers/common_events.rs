@@ -121,6 +121,39 @@ pub fn handle_right_event(app: &mut App) {
             RouteId::Home => {
                 app.set_current_route_state(Some(ActiveBlock::Empty), Some(ActiveBlock::Home));
             }
+            RouteId::Login => {
+                app.set_current_route_state(Some(ActiveBlock::Empty), Some(ActiveBlock::Login));
+            }
+            RouteId::Profiles => {
+                app.set_current_route_state(Some(ActiveBlock::Empty), Some(ActiveBlock::Profiles));
+            }
+            RouteId::User => {
+                app.set_current_route_state(Some(ActiveBlock::Empty), Some(ActiveBlock::User));
+            }
+            RouteId::Discover => {
+                app.set_current_route_state(Some(ActiveBlock::Empty), Some(ActiveBlock::Discover));
+            }
+            RouteId::History => {
+                app.set_current_route_state(Some(ActiveBlock::Empty), Some(ActiveBlock::History));
+            }
+            RouteId::Charts => {
+                app.set_current_route_state(Some(ActiveBlock::Empty), Some(ActiveBlock::Charts));
+            }
+            RouteId::CategoryPicker => {
+                app.set_current_route_state(
+                    Some(ActiveBlock::Empty),
+                    Some(ActiveBlock::CategoryPicker),
+                );
+            }
+            RouteId::Comments => {
+                app.set_current_route_state(Some(ActiveBlock::Empty), Some(ActiveBlock::Comments));
+            }
+            RouteId::PlaylistPicker => {
+                app.set_current_route_state(
+                    Some(ActiveBlock::Empty),
+                    Some(ActiveBlock::PlaylistPicker),
+                );
+            }
             RouteId::Error => {}
             _ => {}
         },
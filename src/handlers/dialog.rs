@@ -0,0 +1,29 @@
+use super::super::app::App;
+use termion::event::Key;
+
+pub fn handler(key: Key, app: &mut App) {
+    match key {
+        Key::Esc => {
+            app.close_dialog();
+        }
+        Key::Char('\n') => {
+            app.submit_dialog();
+        }
+        Key::Ctrl('u') => {
+            if let Some(dialog) = &mut app.dialog {
+                dialog.input.clear();
+            }
+        }
+        Key::Backspace => {
+            if let Some(dialog) = &mut app.dialog {
+                dialog.input.pop();
+            }
+        }
+        Key::Char(c) => {
+            if let Some(dialog) = &mut app.dialog {
+                dialog.input.push(c);
+            }
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,106 @@
+use super::super::app::{ActiveBlock, App, DiscoverSection, RouteId, TrackTable};
+use super::common_events;
+use termion::event::Key;
+
+pub fn handler(key: Key, app: &mut App) {
+    match key {
+        k if common_events::left_event(k) => common_events::handle_left_event(app),
+        k if common_events::down_event(k) => {
+            if let Some(discover) = &mut app.discover {
+                match discover.section {
+                    DiscoverSection::Playlists => {
+                        discover.selected_playlist_index = common_events::on_down_press_handler(
+                            &discover.playlists,
+                            Some(discover.selected_playlist_index),
+                        );
+                    }
+                    DiscoverSection::Songs => {
+                        discover.selected_song_index = common_events::on_down_press_handler(
+                            &discover.songs,
+                            Some(discover.selected_song_index),
+                        );
+                    }
+                    DiscoverSection::Programs => {
+                        discover.selected_program_index = common_events::on_down_press_handler(
+                            &discover.programs,
+                            Some(discover.selected_program_index),
+                        );
+                    }
+                }
+            }
+        }
+        k if common_events::up_event(k) => {
+            if let Some(discover) = &mut app.discover {
+                match discover.section {
+                    DiscoverSection::Playlists => {
+                        discover.selected_playlist_index = common_events::on_up_press_handler(
+                            &discover.playlists,
+                            Some(discover.selected_playlist_index),
+                        );
+                    }
+                    DiscoverSection::Songs => {
+                        discover.selected_song_index = common_events::on_up_press_handler(
+                            &discover.songs,
+                            Some(discover.selected_song_index),
+                        );
+                    }
+                    DiscoverSection::Programs => {
+                        discover.selected_program_index = common_events::on_up_press_handler(
+                            &discover.programs,
+                            Some(discover.selected_program_index),
+                        );
+                    }
+                }
+            }
+        }
+        // switch section
+        Key::Char('\t') => {
+            if let Some(discover) = &mut app.discover {
+                discover.section = discover.section.next();
+            }
+        }
+        // playlist open track table
+        // new song open track table of all new songs
+        // dj program open program list of its radio
+        Key::Char('\n') => {
+            let discover = match &app.discover {
+                Some(discover) => discover.clone(),
+                None => return,
+            };
+            match discover.section {
+                DiscoverSection::Playlists => {
+                    let playlist = discover.playlists.get(discover.selected_playlist_index);
+                    if let Some(playlist_id) = playlist.and_then(|p| p.id) {
+                        app.get_playlist_tracks(playlist_id.to_string());
+                    }
+                }
+                DiscoverSection::Songs => {
+                    if !discover.songs.is_empty() {
+                        app.track_table = TrackTable {
+                            tracks: discover.songs,
+                            selected_index: discover.selected_song_index,
+                            name: "New Songs".to_string(),
+                            playlist_id: None,
+                        };
+                        app.push_navigation_stack(RouteId::TrackTable, ActiveBlock::TrackTable);
+                    }
+                }
+                DiscoverSection::Programs => {
+                    if let Some(program) = discover.programs.get(discover.selected_program_index) {
+                        app.get_djradio_programs(program.program.radio.to_owned(), 500, 0);
+                        app.push_navigation_stack(RouteId::DjProgram, ActiveBlock::DjProgram);
+                    }
+                }
+            }
+        }
+        // add new song to playlist
+        Key::Alt('a') => app.add_selected_to_playlist(),
+        // hot playlists of all users
+        Key::Char('m') => {
+            let limit = (app.block_height - 4) as i32;
+            app.get_top_playlist(limit, 0);
+            app.push_navigation_stack(RouteId::Playlist, ActiveBlock::Playlist);
+        }
+        _ => {}
+    }
+}
@@ -1,5 +1,6 @@
 use super::super::app::{App, TrackTable};
 use super::super::model::artist::Artist;
+use super::super::model::comment::CommentType;
 use super::super::model::playlist::Track;
 use super::common_events;
 use termion::event::Key;
@@ -35,7 +36,7 @@ pub fn handler(key: Key, app: &mut App) {
                         let artist = Artist {
                             id: item.radio.id as i32,
                             name: item.radio.name.to_string(),
-                            alias: None,
+                            ..Default::default()
                         };
                         Track {
                             name: Some(item.mainSong.name.to_string()),
@@ -43,6 +44,8 @@ pub fn handler(key: Key, app: &mut App) {
                             id: Some(item.mainSong.id as i64),
                             artists: Some(vec![artist]),
                             album: None,
+                            reason: None,
+                            last_rank: None,
                         }
                     })
                     .collect::<Vec<Track>>();
@@ -51,12 +54,26 @@ pub fn handler(key: Key, app: &mut App) {
                         tracks: track_list.to_owned(),
                         selected_index: djprogram_list.selected_index,
                         name: "dj program".to_string(),
+                        playlist_id: None,
                     };
                     app.start_playback(djprogram.to_owned());
                     app.fm_state = false;
                 }
             };
         }
+        Key::Char('c') => {
+            let program = match &app.program_list {
+                Some(djprogram_list) => djprogram_list
+                    .dj_programs
+                    .get(djprogram_list.selected_index)
+                    .cloned(),
+                None => None,
+            };
+            if let Some(program) = program {
+                let name = program.mainSong.name.to_string();
+                app.show_comments(CommentType::DjProgram, program.id as i64, name);
+            }
+        }
         _ => {}
     }
 }
@@ -7,6 +7,9 @@ pub fn handler(key: Key, app: &mut App) {
         Key::Char('\n') => {
             let current_hovered = app.get_current_route().hovered_block;
             app.set_current_route_state(Some(current_hovered), None);
+            if current_hovered == ActiveBlock::Search {
+                app.search_input_changed();
+            }
         }
         k if common_events::left_event(k) => match app.get_current_route().hovered_block {
             ActiveBlock::Artist
@@ -19,6 +22,15 @@ pub fn handler(key: Key, app: &mut App) {
             | ActiveBlock::Playing
             | ActiveBlock::DjRadio
             | ActiveBlock::DjProgram
+            | ActiveBlock::Login
+            | ActiveBlock::Profiles
+            | ActiveBlock::PlaylistPicker
+            | ActiveBlock::Comments
+            | ActiveBlock::User
+            | ActiveBlock::Discover
+            | ActiveBlock::History
+            | ActiveBlock::Charts
+            | ActiveBlock::CategoryPicker
             | ActiveBlock::TrackTable => {
                 app.set_current_route_state(None, Some(ActiveBlock::Recommend));
             }
@@ -41,6 +53,10 @@ pub fn handler(key: Key, app: &mut App) {
             | ActiveBlock::SearchResult
             | ActiveBlock::Playlist
             | ActiveBlock::PersonalFm
+            | ActiveBlock::Login
+            | ActiveBlock::Profiles
+            | ActiveBlock::PlaylistPicker
+            | ActiveBlock::Comments
             | ActiveBlock::TrackTable => {
                 app.set_current_route_state(None, Some(ActiveBlock::Search));
             }
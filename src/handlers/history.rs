@@ -0,0 +1,59 @@
+use super::super::app::{App, TrackTable};
+use super::common_events;
+use termion::event::Key;
+
+pub fn handler(key: Key, app: &mut App) {
+    match key {
+        k if common_events::left_event(k) => common_events::handle_left_event(app),
+        k if common_events::down_event(k) => {
+            if let Some(history) = &mut app.history {
+                let next_index = common_events::on_down_press_handler(
+                    &history.tracks,
+                    Some(history.selected_index),
+                );
+                history.selected_index = next_index;
+            }
+        }
+        k if common_events::up_event(k) => {
+            if let Some(history) = &mut app.history {
+                let next_index = common_events::on_up_press_handler(
+                    &history.tracks,
+                    Some(history.selected_index),
+                );
+                history.selected_index = next_index;
+            }
+        }
+        // switch recent, weekly ranking and all time ranking
+        Key::Char('\t') => app.switch_history_tab(),
+        // add track to playlist
+        Key::Alt('a') => app.add_selected_to_playlist(),
+        // song radio from selected track
+        Key::Alt('r') => {
+            let track = match &app.history {
+                Some(history) => history.tracks.get(history.selected_index).cloned(),
+                None => None,
+            };
+            if let Some(track) = track {
+                app.start_radio(track, None);
+            }
+        }
+        // play tracks of current tab as queue
+        Key::Char('\n') => {
+            let history = match &app.history {
+                Some(history) => history.clone(),
+                None => return,
+            };
+            if let Some(track) = history.tracks.get(history.selected_index) {
+                app.my_playlist = TrackTable {
+                    tracks: history.tracks.to_owned(),
+                    selected_index: history.selected_index,
+                    name: "history".to_string(),
+                    playlist_id: None,
+                };
+                app.start_playback(track.to_owned());
+                app.fm_state = false;
+            }
+        }
+        _ => {}
+    }
+}
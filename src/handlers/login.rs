@@ -0,0 +1,70 @@
+use super::super::app::{App, LoginMode};
+use termion::event::Key;
+
+pub fn handler(key: Key, app: &mut App) {
+    match key {
+        Key::Esc => {
+            app.hover_mode();
+        }
+        // switch login mode
+        Key::Left => {
+            let mode = match app.login_form.mode {
+                LoginMode::QrCode => LoginMode::Sms,
+                LoginMode::Password => LoginMode::QrCode,
+                LoginMode::Sms => LoginMode::Password,
+            };
+            app.set_login_mode(mode);
+        }
+        Key::Right => {
+            let mode = match app.login_form.mode {
+                LoginMode::QrCode => LoginMode::Password,
+                LoginMode::Password => LoginMode::Sms,
+                LoginMode::Sms => LoginMode::QrCode,
+            };
+            app.set_login_mode(mode);
+        }
+        // switch input field
+        Key::Char('\t') | Key::Down => {
+            let count = app.login_form.field_count();
+            if count > 0 {
+                app.login_form.selected_field = (app.login_form.selected_field + 1) % count;
+            }
+        }
+        Key::BackTab | Key::Up => {
+            let count = app.login_form.field_count();
+            if count > 0 {
+                app.login_form.selected_field =
+                    (app.login_form.selected_field + count - 1) % count;
+            }
+        }
+        Key::Char('\n') => match app.login_form.mode {
+            LoginMode::QrCode => {}
+            LoginMode::Password => app.password_login(),
+            LoginMode::Sms => app.captcha_login(),
+        },
+        // send sms code
+        Key::Ctrl('s') if app.login_form.mode == LoginMode::Sms => {
+            app.send_login_captcha();
+        }
+        // refresh qrcode
+        Key::Char('r') if app.login_form.mode == LoginMode::QrCode => {
+            app.qr_login();
+        }
+        Key::Ctrl('u') => {
+            if let Some(input) = app.login_form.selected_input() {
+                input.clear();
+            }
+        }
+        Key::Backspace => {
+            if let Some(input) = app.login_form.selected_input() {
+                input.pop();
+            }
+        }
+        Key::Char(c) => {
+            if let Some(input) = app.login_form.selected_input() {
+                input.push(c);
+            }
+        }
+        _ => {}
+    }
+}
@@ -2,18 +2,28 @@ mod album_tracks;
 mod albumlist;
 mod artist;
 mod artistlist;
+mod category_picker;
+mod charts;
+mod comments;
 mod common_events;
+mod dialog;
+mod discover;
 mod djprogram;
 mod djradio;
 mod empty;
 mod fm;
+mod history;
 mod home;
+mod login;
 mod my_playlist;
 mod playlist;
+mod playlist_picker;
+mod profiles;
 mod recommend;
 mod search;
 mod search_results;
 mod track;
+mod user;
 
 use super::app::{Action, ActiveBlock, App, RouteId};
 use termion::event::Key;
@@ -25,6 +35,14 @@ pub enum TrackState {
 }
 
 pub fn handle_app(key: Key, app: &mut App) {
+    handle_key(key, app);
+    // login expired while handling the key, login again and retry the request
+    if app.session_expired() && app.get_current_route().id != RouteId::Login {
+        app.relogin();
+    }
+}
+
+fn handle_key(key: Key, app: &mut App) {
     // get current route
     let current_route = app.get_current_route();
     match current_route.active_block {
@@ -36,6 +54,8 @@ pub fn handle_app(key: Key, app: &mut App) {
                 search::handler(key, app);
             }
         },
+        ActiveBlock::Login => login::handler(key, app),
+        ActiveBlock::Dialog => dialog::handler(key, app),
         _ => match key {
             // means space
             Key::Char(' ') => {
@@ -59,6 +79,7 @@ pub fn handle_app(key: Key, app: &mut App) {
             }
             Key::Char('/') => {
                 app.set_current_route_state(Some(ActiveBlock::Search), Some(ActiveBlock::Search));
+                app.search_input_changed();
             }
             Key::Char('r') => {
                 app.repeat();
@@ -85,11 +106,15 @@ pub fn handle_app(key: Key, app: &mut App) {
                 app.hover_mode();
             }
             Key::Ctrl('y') => {
-                app.like_current(Action::Subscribe);
+                app.toggle_like_current();
             }
             Key::Ctrl('d') => {
                 app.like_current(Action::Unsubscribe);
             }
+            // switch account profile
+            Key::Alt('u') => {
+                app.show_profiles();
+            }
             Key::Char('a') => {
                 let album_id = match &app.current_playing {
                     Some(track) => track.album.to_owned().unwrap().id,
@@ -153,6 +178,30 @@ fn handle_block_events(key: Key, app: &mut App) {
         ActiveBlock::DjProgram => {
             djprogram::handler(key, app);
         }
+        ActiveBlock::Profiles => {
+            profiles::handler(key, app);
+        }
+        ActiveBlock::PlaylistPicker => {
+            playlist_picker::handler(key, app);
+        }
+        ActiveBlock::Comments => {
+            comments::handler(key, app);
+        }
+        ActiveBlock::User => {
+            user::handler(key, app);
+        }
+        ActiveBlock::Discover => {
+            discover::handler(key, app);
+        }
+        ActiveBlock::History => {
+            history::handler(key, app);
+        }
+        ActiveBlock::Charts => {
+            charts::handler(key, app);
+        }
+        ActiveBlock::CategoryPicker => {
+            category_picker::handler(key, app);
+        }
         _ => {}
     }
 }
@@ -1,4 +1,5 @@
 use super::super::app::App;
+use super::super::model::comment::CommentType;
 use super::common_events;
 use termion::event::Key;
 
@@ -37,6 +38,31 @@ pub fn handler(key: Key, app: &mut App) {
                 }
             };
         }
+        Key::Char('c') => {
+            let playlist = match (&app.playlists, app.selected_playlist_index) {
+                (Some(playlists), Some(index)) => playlists.get(index).cloned(),
+                _ => None,
+            };
+            if let Some(playlist) = playlist {
+                let name = playlist.name.unwrap_or_default();
+                app.show_comments(CommentType::Playlist, playlist.id.unwrap_or(0), name);
+            }
+        }
+        // create public or private playlist
+        Key::Alt('n') => app.open_create_playlist(false),
+        Key::Alt('p') => app.open_create_playlist(true),
+        Key::Alt('e') => app.open_rename_playlist(),
+        Key::Alt('d') => app.open_delete_playlist(),
+        // creator of playlist
+        Key::Char('u') => {
+            let playlist = match (&app.playlists, app.selected_playlist_index) {
+                (Some(playlists), Some(index)) => playlists.get(index).cloned(),
+                _ => None,
+            };
+            if let Some(user_id) = playlist.and_then(|p| p.userId) {
+                app.show_user(user_id);
+            }
+        }
         _ => {}
     }
 }
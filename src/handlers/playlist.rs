@@ -1,4 +1,5 @@
 use super::super::app::{Action, App};
+use super::super::model::comment::CommentType;
 use super::common_events;
 use termion::event::Key;
 
@@ -32,6 +33,26 @@ pub fn handler(key: Key, app: &mut App) {
                 }
             };
         }
+        Key::Char('c') => {
+            let playlist = match &app.playlist_list {
+                Some(playlists) => playlists.playlists.get(playlists.selected_index).cloned(),
+                None => None,
+            };
+            if let Some(playlist) = playlist {
+                let name = playlist.name.unwrap_or_default();
+                app.show_comments(CommentType::Playlist, playlist.id.unwrap_or(0), name);
+            }
+        }
+        // creator of playlist
+        Key::Char('u') => {
+            let playlist = match &app.playlist_list {
+                Some(playlists) => playlists.playlists.get(playlists.selected_index).cloned(),
+                None => None,
+            };
+            if let Some(user_id) = playlist.and_then(|p| p.creator).and_then(|c| c.userId) {
+                app.show_user(user_id);
+            }
+        }
         Key::Ctrl('f') => {
             let limit = (app.block_height - 4) as i32;
             if let Some(playlists) = &app.playlist_list {
@@ -48,6 +69,10 @@ pub fn handler(key: Key, app: &mut App) {
                 app.get_top_playlist(limit, next_page)
             };
         }
+        // choose category
+        Key::Char('g') => app.show_category_picker(),
+        // order by hot or new
+        Key::Char('o') => app.toggle_playlist_order(),
         Key::Alt('s') => match &app.playlist_list.clone() {
             Some(playlists) => {
                 if let Some(playlist) = playlists.playlists.get(playlists.selected_index.to_owned())
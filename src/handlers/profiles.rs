@@ -0,0 +1,40 @@
+use super::super::app::App;
+use super::common_events;
+use termion::event::Key;
+
+pub fn handler(key: Key, app: &mut App) {
+    match key {
+        k if common_events::left_event(k) => common_events::handle_left_event(app),
+        k if common_events::down_event(k) => {
+            if let Some(profile_list) = &mut app.profile_list {
+                let next_index = common_events::on_down_press_handler(
+                    &profile_list.profiles,
+                    Some(profile_list.selected_index),
+                );
+                profile_list.selected_index = next_index;
+            }
+        }
+        k if common_events::up_event(k) => {
+            if let Some(profile_list) = &mut app.profile_list {
+                let next_index = common_events::on_up_press_handler(
+                    &profile_list.profiles,
+                    Some(profile_list.selected_index),
+                );
+                profile_list.selected_index = next_index;
+            }
+        }
+        Key::Char('\n') => {
+            let profile = match &app.profile_list {
+                Some(profile_list) => profile_list
+                    .profiles
+                    .get(profile_list.selected_index)
+                    .cloned(),
+                None => None,
+            };
+            if let Some(profile) = profile {
+                app.switch_profile(profile);
+            }
+        }
+        _ => {}
+    }
+}
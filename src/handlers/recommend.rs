@@ -21,29 +21,35 @@ pub fn handler(key: Key, app: &mut App) {
         }
         // recommend list
         // you can go Discover music
+        // you can go Liked Songs
+        // you can go Daily Recommend
         // you can go Personal FM
+        // you can go Charts
+        // you can go History
         // you can go you playlists
         Key::Char('\n') => {
             let limit = (app.block_height - 4) as i32;
             match app.recommend.selected_index {
                 0 => app.push_navigation_stack(RouteId::MyPlaylists, ActiveBlock::MyPlaylists),
-                1 => {
-                    app.get_top_playlist(limit, 0);
-                    app.push_navigation_stack(RouteId::Playlist, ActiveBlock::Playlist)
-                }
-                2 => app.set_fm_mode(),
-                3 => {
-                    app.get_top_albums(limit, 0);
+                1 => app.show_liked_songs(),
+                2 => app.get_recommend_songs(),
+                3 => app.show_discover(),
+                4 => app.set_fm_mode(),
+                5 => {
+                    app.get_top_albums(app.album_page_size() as i32, 0);
                     app.push_navigation_stack(RouteId::AlbumList, ActiveBlock::AlbumList);
                 }
-                4 => {
+                6 => {
                     app.get_top_artists(limit, 0);
                     app.push_navigation_stack(RouteId::ArtistList, ActiveBlock::ArtistList);
                 }
-                5 => {
+                7 => {
                     app.get_sub_dj_radio(limit, 0);
                     app.push_navigation_stack(RouteId::DjRadio, ActiveBlock::DjRadio);
                 }
+                8 => app.show_charts(),
+                9 => app.show_history(),
+                10 => app.show_user(app.user_id as i64),
                 _ => {}
             }
         }
@@ -1,10 +1,17 @@
 extern crate unicode_width;
 
-use super::super::app::{ActiveBlock, App, RouteId};
+use super::super::app::{ActiveBlock, App};
 use std::convert::TryInto;
 use termion::event::Key;
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
+// replace input and move cursor to the end
+fn set_input(app: &mut App, input: &str) {
+    app.input = input.chars().collect();
+    app.input_idx = app.input.len();
+    app.input_cursor_position = UnicodeWidthStr::width(input).try_into().unwrap();
+}
+
 // Handle events when the search input block is active
 pub fn handler(key: Key, app: &mut App) {
     match key {
@@ -12,6 +19,7 @@ pub fn handler(key: Key, app: &mut App) {
             app.input = vec![];
             app.input_idx = 0;
             app.input_cursor_position = 0;
+            app.search_input_changed();
         }
         Key::Ctrl('e') => {
             app.input_idx = app.input.len();
@@ -49,86 +57,33 @@ pub fn handler(key: Key, app: &mut App) {
                 app.input_idx -= 1;
                 let width: u16 = UnicodeWidthChar::width(last_c).unwrap() as u16;
                 app.input_cursor_position -= width;
+                app.search_input_changed();
             }
         }
         Key::Delete => {
             if !app.input.is_empty() && app.input_idx < app.input.len() {
                 app.input.remove(app.input_idx);
+                app.search_input_changed();
             }
         }
+        // navigate suggestions
+        Key::Down => {
+            app.select_suggestion(true);
+        }
+        Key::Up => {
+            app.select_suggestion(false);
+        }
         Key::Char('\n') => {
-            let limit = (app.block_height - 5) as i32;
-            // no input no search
-            if app.input.len() > 0 {
-                // search tracks
-                let input: String = app.input.iter().collect();
-                match app
-                    .cloud_music
-                    .as_ref()
-                    .unwrap()
-                    .search_track(&input, limit, 0)
-                {
-                    Ok(result) => {
-                        app.search_results.tracks = Some(result.songs.unwrap_or(vec![]));
-                    }
-                    Err(e) => {
-                        app.handle_error(e);
-                    }
+            let input = match app.selected_suggestion() {
+                Some(keyword) => {
+                    set_input(app, &keyword);
+                    keyword
                 }
-                match app
-                    .cloud_music
-                    .as_ref()
-                    .unwrap()
-                    .search_playlist(&input, limit, 0)
-                {
-                    Ok(result) => {
-                        app.search_results.playlists = Some(result.playlists.unwrap_or(vec![]));
-                    }
-                    Err(e) => {
-                        app.handle_error(e);
-                    }
-                }
-                match app
-                    .cloud_music
-                    .as_ref()
-                    .unwrap()
-                    .search_artist(&input, limit, 0)
-                {
-                    Ok(result) => {
-                        app.search_results.artists = Some(result.artists.unwrap_or(vec![]));
-                    }
-                    Err(e) => {
-                        app.handle_error(e);
-                    }
-                }
-                match app
-                    .cloud_music
-                    .as_ref()
-                    .unwrap()
-                    .search_album(&input, limit, 0)
-                {
-                    Ok(result) => {
-                        app.search_results.albums = Some(result.albums.unwrap_or(vec![]));
-                    }
-                    Err(e) => {
-                        app.handle_error(e);
-                    }
-                }
-                match app
-                    .cloud_music
-                    .as_ref()
-                    .unwrap()
-                    .search_djradio(&input, limit, 0)
-                {
-                    Ok(result) => {
-                        app.search_results.djradios = Some(result.djRadios.unwrap_or(vec![]));
-                    }
-                    Err(e) => {
-                        app.handle_error(e);
-                    }
-                }
-                app.selected_playlist_index = None;
-                app.push_navigation_stack(RouteId::Search, ActiveBlock::SearchResult);
+                None => app.input.iter().collect(),
+            };
+            // no input no search
+            if !input.is_empty() {
+                app.search(input);
             }
         }
         // search input
@@ -137,6 +92,7 @@ pub fn handler(key: Key, app: &mut App) {
             app.input_idx += 1;
             let width: u16 = UnicodeWidthChar::width(c).unwrap().try_into().unwrap();
             app.input_cursor_position += width;
+            app.search_input_changed();
         }
         _ => {}
     }
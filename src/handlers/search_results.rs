@@ -1,83 +1,76 @@
-use super::super::app::{ActiveBlock, App, RouteId, TrackTable};
+use super::super::app::{ActiveBlock, App, RouteId, SearchList, TrackTable};
+use super::super::model::comment::CommentType;
 use super::common_events;
 use termion::event::Key;
 
+// move down in list, true if next page should be loaded first
+fn move_down<T>(list: &mut Option<SearchList<T>>) -> bool {
+    match list {
+        Some(list) if list.selected_index + 1 >= list.items.len() && list.has_more() => true,
+        Some(list) => {
+            list.selected_index =
+                common_events::on_down_press_handler(&list.items, Some(list.selected_index));
+            false
+        }
+        None => false,
+    }
+}
+
+fn move_up<T>(list: &mut Option<SearchList<T>>) {
+    if let Some(list) = list {
+        list.selected_index =
+            common_events::on_up_press_handler(&list.items, Some(list.selected_index));
+    }
+}
+
+fn move_down_tab(app: &mut App) -> bool {
+    let results = &mut app.search_results;
+    match app.tabs.index {
+        0 => move_down(&mut results.tracks),
+        1 => move_down(&mut results.artists),
+        2 => move_down(&mut results.albums),
+        3 => move_down(&mut results.playlists),
+        4 => move_down(&mut results.djradios),
+        5 => move_down(&mut results.lyrics),
+        6 => move_down(&mut results.users),
+        _ => false,
+    }
+}
+
+fn move_up_tab(app: &mut App) {
+    let results = &mut app.search_results;
+    match app.tabs.index {
+        0 => move_up(&mut results.tracks),
+        1 => move_up(&mut results.artists),
+        2 => move_up(&mut results.albums),
+        3 => move_up(&mut results.playlists),
+        4 => move_up(&mut results.djradios),
+        5 => move_up(&mut results.lyrics),
+        6 => move_up(&mut results.users),
+        _ => {}
+    }
+}
+
 pub fn handler(key: Key, app: &mut App) {
     match key {
         Key::Esc => {
             app.set_current_route_state(Some(ActiveBlock::Empty), Some(ActiveBlock::SearchResult));
         }
         k if common_events::down_event(k) => {
-            // track tab
-            if app.tabs.index == 0 {
-                let next_index = common_events::on_down_press_handler(
-                    &app.search_results.tracks.as_ref().unwrap_or(&vec![]),
-                    Some(app.search_results.selected_tracks_index),
-                );
-                app.search_results.selected_tracks_index = next_index;
-            } else if app.tabs.index == 1 {
-                let next_index = common_events::on_down_press_handler(
-                    &app.search_results.artists.as_ref().unwrap_or(&vec![]),
-                    Some(app.search_results.selected_artists_index),
-                );
-                app.search_results.selected_artists_index = next_index;
-            } else if app.tabs.index == 2 {
-                let next_index = common_events::on_down_press_handler(
-                    &app.search_results.albums.as_ref().unwrap_or(&vec![]),
-                    Some(app.search_results.selected_albums_index),
-                );
-                app.search_results.selected_albums_index = next_index;
-            } else if app.tabs.index == 3 {
-                let next_index = common_events::on_down_press_handler(
-                    &app.search_results.playlists.as_ref().unwrap_or(&vec![]),
-                    Some(app.search_results.selected_playlists_index),
-                );
-                app.search_results.selected_playlists_index = next_index;
-            } else if app.tabs.index == 4 {
-                let next_index = common_events::on_down_press_handler(
-                    &app.search_results.djradios.as_ref().unwrap_or(&vec![]),
-                    Some(app.search_results.selected_djradio_index),
-                );
-                app.search_results.selected_djradio_index = next_index;
+            let load_more = move_down_tab(app);
+            // scrolled past the end, load next page then move into it
+            if load_more {
+                app.search_more();
+                move_down_tab(app);
             }
         }
         k if common_events::up_event(k) => {
-            // track tab
-            if app.tabs.index == 0 {
-                let next_index = common_events::on_up_press_handler(
-                    &app.search_results.tracks.as_ref().unwrap_or(&vec![]),
-                    Some(app.search_results.selected_tracks_index),
-                );
-                app.search_results.selected_tracks_index = next_index;
-            } else if app.tabs.index == 1 {
-                let next_index = common_events::on_up_press_handler(
-                    &app.search_results.artists.as_ref().unwrap_or(&vec![]),
-                    Some(app.search_results.selected_artists_index),
-                );
-                app.search_results.selected_artists_index = next_index;
-            } else if app.tabs.index == 2 {
-                let next_index = common_events::on_up_press_handler(
-                    &app.search_results.albums.as_ref().unwrap_or(&vec![]),
-                    Some(app.search_results.selected_albums_index),
-                );
-                app.search_results.selected_albums_index = next_index;
-            } else if app.tabs.index == 3 {
-                let next_index = common_events::on_up_press_handler(
-                    &app.search_results.playlists.as_ref().unwrap_or(&vec![]),
-                    Some(app.search_results.selected_playlists_index),
-                );
-                app.search_results.selected_playlists_index = next_index;
-            } else if app.tabs.index == 4 {
-                let next_index = common_events::on_up_press_handler(
-                    &app.search_results.djradios.as_ref().unwrap_or(&vec![]),
-                    Some(app.search_results.selected_djradio_index),
-                );
-                app.search_results.selected_djradio_index = next_index;
-            }
+            move_up_tab(app);
         }
         k if common_events::right_event(k) => {
             let next = (app.tabs.index + 1) % app.tabs.titles.len();
             app.tabs.index = next;
+            app.search_current_tab();
         }
         k if common_events::left_event(k) => {
             let next = if app.tabs.index > 0 {
@@ -86,255 +79,127 @@ pub fn handler(key: Key, app: &mut App) {
                 app.tabs.titles.len() - 1
             };
             app.tabs.index = next;
+            app.search_current_tab();
         }
         Key::Char('\n') => {
+            let results = app.search_results.clone();
             if app.tabs.index == 0 {
-                match &app.search_results.tracks.clone() {
-                    Some(tracks) => {
-                        match tracks.get(app.search_results.selected_tracks_index.to_owned()) {
-                            Some(track_playing) => {
-                                app.start_playback(track_playing.to_owned());
-                                app.fm_state = false;
-                                app.my_playlist = TrackTable {
-                                    tracks: app.search_results.tracks.to_owned().unwrap(),
-                                    selected_index: app.search_results.selected_tracks_index,
-                                    name: "search result".to_string(),
-                                };
-                            }
-                            None => {}
-                        }
+                if let Some(tracks) = results.tracks {
+                    if let Some(track_playing) = tracks.selected() {
+                        app.start_playback(track_playing.to_owned());
+                        app.fm_state = false;
+                        app.my_playlist = TrackTable {
+                            tracks: tracks.items.to_owned(),
+                            selected_index: tracks.selected_index,
+                            name: "search result".to_string(),
+                            playlist_id: None,
+                        };
                     }
-                    None => {}
                 }
             } else if app.tabs.index == 1 {
-                match &app.search_results.artists.clone() {
-                    Some(artists) => {
-                        match artists.get(app.search_results.selected_artists_index.to_owned()) {
-                            Some(artist) => {
-                                let artist_id = artist.id.to_owned();
-                                app.get_artist_albums(artist_id.to_string());
-                            }
-                            None => {}
-                        }
-                    }
-                    None => {}
+                if let Some(artist) = results.artists.as_ref().and_then(|l| l.selected()) {
+                    app.show_artist(artist.id.to_string());
                 }
             } else if app.tabs.index == 2 {
-                match &app.search_results.albums.clone() {
-                    Some(albums) => {
-                        match albums.get(app.search_results.selected_albums_index.to_owned()) {
-                            Some(album) => {
-                                let album_id = album.id.to_owned().unwrap();
-                                app.get_album_tracks(album_id.to_string());
-                            }
-                            None => {}
-                        }
-                    }
-                    None => {}
+                if let Some(album) = results.albums.as_ref().and_then(|l| l.selected()) {
+                    let album_id = album.id.to_owned().unwrap();
+                    app.get_album_tracks(album_id.to_string());
                 }
             } else if app.tabs.index == 3 {
-                match &app.search_results.playlists.clone() {
-                    Some(playlists) => {
-                        match playlists.get(app.search_results.selected_playlists_index.to_owned())
-                        {
-                            Some(playlist) => {
-                                let playlist_id = playlist.id.to_owned().unwrap();
-                                app.get_playlist_tracks(playlist_id.to_string());
-                            }
-                            None => {}
-                        }
-                    }
-                    None => {}
+                if let Some(playlist) = results.playlists.as_ref().and_then(|l| l.selected()) {
+                    let playlist_id = playlist.id.to_owned().unwrap();
+                    app.get_playlist_tracks(playlist_id.to_string());
                 }
             } else if app.tabs.index == 4 {
-                match &app.search_results.djradios.clone() {
-                    Some(djradios) => {
-                        match djradios.get(app.search_results.selected_djradio_index.to_owned()) {
-                            Some(djradio) => {
-                                app.get_djradio_programs(djradio.to_owned(), 500, 0);
-                                app.push_navigation_stack(
-                                    RouteId::DjProgram,
-                                    ActiveBlock::DjProgram,
-                                );
-                            }
-                            None => {}
-                        }
-                    }
-                    None => {}
-                }
-            }
-        }
-        Key::Ctrl('f') => {
-            let limit = (app.block_height - 5) as i32;
-            let input: String = app.input.iter().collect();
-            if app.tabs.index == 0 {
-                let page = app.search_results.selected_tracks_page as i32;
-                let next_page = page + 1;
-                // search tracks
-                match app.cloud_music.as_ref().unwrap().search_track(
-                    &input,
-                    limit,
-                    next_page * limit,
-                ) {
-                    Ok(result) => match result.songs {
-                        Some(tracks) => {
-                            app.search_results.tracks = Some(tracks);
-                            app.search_results.selected_tracks_page = next_page as usize;
-                        }
-                        None => {}
-                    },
-                    Err(e) => {
-                        app.handle_error(e);
-                    }
-                }
-            }
-            if app.tabs.index == 1 {
-                let page = app.search_results.selected_artists_page as i32;
-                let next_page = page + 1;
-                // search artist
-                match app.cloud_music.as_ref().unwrap().search_artist(
-                    &input,
-                    limit,
-                    next_page * limit,
-                ) {
-                    Ok(result) => match result.artists {
-                        Some(artists) => {
-                            app.search_results.artists = Some(artists);
-                            app.search_results.selected_artists_page = next_page as usize;
-                        }
-                        None => {}
-                    },
-                    Err(e) => {
-                        app.handle_error(e);
-                    }
+                if let Some(djradio) = results.djradios.as_ref().and_then(|l| l.selected()) {
+                    app.get_djradio_programs(djradio.to_owned(), 500, 0);
+                    app.push_navigation_stack(RouteId::DjProgram, ActiveBlock::DjProgram);
                 }
-            }
-            if app.tabs.index == 2 {
-                let page = app.search_results.selected_albums_page as i32;
-                let next_page = page + 1;
-                // search tracks
-                match app.cloud_music.as_ref().unwrap().search_album(
-                    &input,
-                    limit,
-                    next_page * limit,
-                ) {
-                    Ok(result) => match result.albums {
-                        Some(albums) => {
-                            app.search_results.albums = Some(albums);
-                            app.search_results.selected_albums_page = next_page as usize;
-                        }
-                        None => {}
-                    },
-                    Err(e) => {
-                        app.handle_error(e);
+            } else if app.tabs.index == 5 {
+                // play tracks of matched lyrics
+                if let Some(lyrics) = results.lyrics {
+                    if let Some(lyric) = lyrics.selected() {
+                        app.start_playback(lyric.track.to_owned());
+                        app.fm_state = false;
+                        app.my_playlist = TrackTable {
+                            tracks: lyrics.items.iter().map(|l| l.track.to_owned()).collect(),
+                            selected_index: lyrics.selected_index,
+                            name: "search result".to_string(),
+                            playlist_id: None,
+                        };
                     }
                 }
-            }
-            if app.tabs.index == 3 {
-                let page = app.search_results.selected_playlists_page as i32;
-                let next_page = page + 1;
-                // search playlist
-                match app.cloud_music.as_ref().unwrap().search_playlist(
-                    &input,
-                    limit,
-                    next_page * limit,
-                ) {
-                    Ok(result) => match result.playlists {
-                        Some(playlists) => {
-                            app.search_results.playlists = Some(playlists);
-                            app.search_results.selected_playlists_page = next_page as usize;
-                        }
-                        None => {}
-                    },
-                    Err(e) => {
-                        app.handle_error(e);
-                    }
+            } else if app.tabs.index == 6 {
+                let user_id = results
+                    .users
+                    .as_ref()
+                    .and_then(|l| l.selected())
+                    .and_then(|u| u.userId);
+                if let Some(user_id) = user_id {
+                    app.show_user(user_id as i64);
                 }
             }
         }
-        Key::Ctrl('b') => {
-            let limit = (app.block_height - 5) as i32;
-            let input: String = app.input.iter().collect();
-            if app.tabs.index == 0 {
-                let page = app.search_results.selected_tracks_page as i32;
-                let next_page = if page < 1 { 0 } else { page - 1 };
-                // search tracks
-                match app.cloud_music.as_ref().unwrap().search_track(
-                    &input,
-                    limit,
-                    next_page * limit,
-                ) {
-                    Ok(result) => match result.songs {
-                        Some(tracks) => {
-                            app.search_results.tracks = Some(tracks);
-                            app.search_results.selected_tracks_page = next_page as usize;
-                        }
-                        None => {}
-                    },
-                    Err(e) => {
-                        app.handle_error(e);
-                    }
-                }
-            }
-            if app.tabs.index == 1 {
-                let page = app.search_results.selected_artists_page as i32;
-                let next_page = if page < 1 { 0 } else { page - 1 };
-                // search tracks
-                match app.cloud_music.as_ref().unwrap().search_artist(
-                    &input,
-                    limit,
-                    next_page * limit,
-                ) {
-                    Ok(result) => match result.artists {
-                        Some(artists) => {
-                            app.search_results.artists = Some(artists);
-                            app.search_results.selected_artists_page = next_page as usize;
-                        }
-                        None => {}
-                    },
-                    Err(e) => {
-                        app.handle_error(e);
-                    }
-                }
+        // add song or lyric track to playlist
+        Key::Alt('a') => app.add_selected_to_playlist(),
+        // song radio from selected song or lyric track
+        Key::Alt('r') => {
+            let results = &app.search_results;
+            let track = match app.tabs.index {
+                0 => results.tracks.as_ref().and_then(|l| l.selected()).cloned(),
+                5 => results
+                    .lyrics
+                    .as_ref()
+                    .and_then(|l| l.selected())
+                    .map(|l| l.track.to_owned()),
+                _ => None,
+            };
+            if let Some(track) = track {
+                app.start_radio(track, None);
             }
-            if app.tabs.index == 2 {
-                let page = app.search_results.selected_albums_page as i32;
-                let next_page = if page < 1 { 0 } else { page - 1 };
-                // search album
-                match app.cloud_music.as_ref().unwrap().search_album(
-                    &input,
-                    limit,
-                    next_page * limit,
-                ) {
-                    Ok(result) => {
-                        app.search_results.albums = Some(result.albums.unwrap_or(vec![]));
-                    }
-                    Err(e) => {
-                        app.handle_error(e);
-                    }
-                }
-                app.search_results.selected_albums_page = next_page as usize;
+        }
+        // comments of selected song, album, playlist or lyric track
+        Key::Char('c') => {
+            let results = &app.search_results;
+            let comment = match app.tabs.index {
+                0 => results
+                    .tracks
+                    .as_ref()
+                    .and_then(|l| l.selected())
+                    .map(|t| (CommentType::Song, t.id.unwrap_or(0), t.name.to_owned())),
+                2 => results
+                    .albums
+                    .as_ref()
+                    .and_then(|l| l.selected())
+                    .map(|a| (CommentType::Album, a.id.unwrap_or(0) as i64, a.name.to_owned())),
+                3 => results
+                    .playlists
+                    .as_ref()
+                    .and_then(|l| l.selected())
+                    .map(|p| (CommentType::Playlist, p.id.unwrap_or(0), p.name.to_owned())),
+                5 => results
+                    .lyrics
+                    .as_ref()
+                    .and_then(|l| l.selected())
+                    .map(|l| &l.track)
+                    .map(|t| (CommentType::Song, t.id.unwrap_or(0), t.name.to_owned())),
+                _ => None,
+            };
+            if let Some((kind, id, name)) = comment {
+                app.show_comments(kind, id, name.unwrap_or_default());
             }
-            if app.tabs.index == 3 {
-                let page = app.search_results.selected_playlists_page as i32;
-                let next_page = if page < 1 { 0 } else { page - 1 };
-                // search playlist
-                match app.cloud_music.as_ref().unwrap().search_playlist(
-                    &input,
-                    limit,
-                    next_page * limit,
-                ) {
-                    Ok(result) => match result.playlists {
-                        Some(playlists) => {
-                            app.search_results.playlists = Some(playlists);
-                            app.search_results.selected_playlists_page = next_page as usize;
-                        }
-                        None => {}
-                    },
-                    Err(e) => {
-                        app.handle_error(e);
-                    }
-                }
+        }
+        // creator of playlist
+        Key::Char('u') if app.tabs.index == 3 => {
+            let user_id = app
+                .search_results
+                .playlists
+                .as_ref()
+                .and_then(|l| l.selected())
+                .and_then(|p| p.creator.as_ref())
+                .and_then(|c| c.userId);
+            if let Some(user_id) = user_id {
+                app.show_user(user_id);
             }
         }
         _ => {}
@@ -1,4 +1,5 @@
 use super::super::app::App;
+use super::super::model::comment::CommentType;
 use super::common_events;
 use termion::event::Key;
 
@@ -32,6 +33,32 @@ pub fn handler(key: Key, app: &mut App) {
             app.start_playback(track_playing);
             app.fm_state = false;
         }
+        // add track to playlist
+        Key::Alt('a') => app.add_selected_to_playlist(),
+        // song radio from selected track
+        Key::Alt('r') => {
+            if let Some(track) = app.track_table.tracks.get(app.track_table.selected_index) {
+                let playlist_id = app.track_table.playlist_id;
+                app.start_radio(track.to_owned(), playlist_id);
+            }
+        }
+        // remove track from playlist
+        Key::Alt('d') => {
+            app.remove_track_from_playlist();
+        }
+        Key::Char('c') => {
+            if let Some(track) = app.track_table.tracks.get(app.track_table.selected_index) {
+                let (id, name) = (track.id.unwrap_or(0), track.name.to_owned().unwrap_or_default());
+                app.show_comments(CommentType::Song, id, name);
+            }
+        }
+        // comments of playlist
+        Key::Char('C') => {
+            if let Some(playlist_id) = app.track_table.playlist_id {
+                let name = app.track_table.name.to_owned();
+                app.show_comments(CommentType::Playlist, playlist_id, name);
+            }
+        }
         _ => {}
     }
 }
@@ -0,0 +1,58 @@
+use super::super::app::App;
+use super::super::model::comment::CommentType;
+use super::common_events;
+use termion::event::Key;
+
+pub fn handler(key: Key, app: &mut App) {
+    match key {
+        k if common_events::left_event(k) => common_events::handle_left_event(app),
+        k if common_events::down_event(k) => {
+            if let Some(user_page) = &mut app.user_page {
+                let next_index = common_events::on_down_press_handler(
+                    &user_page.playlists,
+                    Some(user_page.selected_index),
+                );
+                user_page.selected_index = next_index;
+            }
+        }
+        k if common_events::up_event(k) => {
+            if let Some(user_page) = &mut app.user_page {
+                let next_index = common_events::on_up_press_handler(
+                    &user_page.playlists,
+                    Some(user_page.selected_index),
+                );
+                user_page.selected_index = next_index;
+            }
+        }
+        Key::Char('\n') => {
+            let playlist = match &app.user_page {
+                Some(user_page) => user_page.playlists.get(user_page.selected_index).cloned(),
+                None => None,
+            };
+            if let Some(playlist_id) = playlist.and_then(|p| p.id) {
+                app.get_playlist_tracks(playlist_id.to_string());
+            }
+        }
+        Key::Char('c') => {
+            let playlist = match &app.user_page {
+                Some(user_page) => user_page.playlists.get(user_page.selected_index).cloned(),
+                None => None,
+            };
+            if let Some(playlist) = playlist {
+                let name = playlist.name.unwrap_or_default();
+                app.show_comments(CommentType::Playlist, playlist.id.unwrap_or(0), name);
+            }
+        }
+        // creator of subscribed playlist
+        Key::Char('u') => {
+            let playlist = match &app.user_page {
+                Some(user_page) => user_page.playlists.get(user_page.selected_index).cloned(),
+                None => None,
+            };
+            if let Some(user_id) = playlist.and_then(|p| p.userId) {
+                app.show_user(user_id);
+            }
+        }
+        _ => {}
+    }
+}
@@ -10,17 +10,16 @@ extern crate log;
 // log panics to find unknown error
 extern crate log_panics;
 
-use dirs;
 use failure::err_msg;
 use log::LevelFilter;
-use std::fs;
 use std::io;
-use std::path::Path;
 use termion::event::Key;
 use termion::raw::IntoRawMode;
 use tui::backend::TermionBackend;
 use tui::Terminal;
 use util::event::{Event, Events};
+use util::paths::{self, Dir};
+use util::settings::default_profile;
 
 mod api;
 mod app;
@@ -36,72 +35,46 @@ use app::{ActiveBlock, App};
 
 use dbus_mpris::{dbus_mpris_handler, DbusMpris};
 
-pub const FILE_NAME: &str = "Settings.toml";
-pub const CONFIG_DIR: &str = ".config";
-pub const APP_CONFIG_DIR: &str = "netease-music-tui";
-
-
 fn main() -> Result<(), failure::Error> {
-    let config_file_path = match dirs::home_dir() {
-        Some(home) => {
-            let path = Path::new(&home);
-            let home_config_dir = path.join(CONFIG_DIR);
-            let app_config_dir = home_config_dir.join(APP_CONFIG_DIR);
-
-            if !home_config_dir.exists() {
-                fs::create_dir(&home_config_dir)?;
-            }
-
-            if !app_config_dir.exists() {
-                fs::create_dir(&app_config_dir)?;
-            }
-            let config_file_path = &app_config_dir.join(FILE_NAME);
-            config_file_path.to_path_buf()
-        }
+    // move files from old locations before read them
+    let migrated = paths::migrate();
+    paths::ensure_dir(Dir::Config)?;
+    let config_file_path = match paths::config_file() {
+        Some(path) => path,
         None => return Err(err_msg("No $HOME directory found for config")),
     };
 
     // init application
     let settings = match config::Config::builder()
-        .add_source(config::File::with_name(&config_file_path.to_string_lossy()))
+        .add_source(config::File::from(config_file_path).required(false))
         .build() {
         Ok(data) => data,
-        Err(_) => return Err(err_msg("Please set your account in config file")),
+        Err(_) => return Err(err_msg("Please check your config file")),
     };
 
     match settings.get_bool("debug") {
         Ok(debug) => {
             if debug {
                 log_panics::init();
-                simple_logging::log_to_file("/var/log/ncmt.log", LevelFilter::Debug)?;
+                paths::ensure_dir(Dir::State)?;
+                if let Some(log_file) = paths::log_file() {
+                    simple_logging::log_to_file(log_file, LevelFilter::Debug)?;
+                }
             }
         }
         Err(e) => error!("{}", e),
     }
 
     info!("start netease cloud music rust client");
+    if let Err(e) = migrated {
+        error!("migrate old files failed: {}", e);
+    }
 
     // init application
     let mut app = App::new();
-    let mut is_first_render = true;
 
-
-    let cloud_music = app.cloud_music.to_owned().unwrap();
-    let profile = match cloud_music.login_status()? {
-        Some(profile) => {
-            app.user_id = profile.userId.unwrap();
-            profile
-        }
-        None => {
-            // need login
-            let username = settings.get::<String>("username")?;
-            let password = settings.get::<String>("password")?;
-            match cloud_music.login(&username, &password) {
-                Ok(profile) => profile,
-                Err(_) => return Err(err_msg("Account/Password Error")),
-            }
-        }
-    };
+    // login with cookie of default profile or account in config
+    app.login_profile(&default_profile());
 
     let stdout = io::stdout().into_raw_mode()?;
     let stdout = termion::input::MouseTerminal::from(stdout);
@@ -143,7 +116,15 @@ fn main() -> Result<(), failure::Error> {
             Event::Input(input) => {
                 match input {
                     Key::Char('q') => {
-                        if app.get_current_route().active_block != ActiveBlock::Search {
+                        let active_block = app.get_current_route().active_block;
+                        // q is input in these blocks
+                        let is_input = matches!(
+                            active_block,
+                            ActiveBlock::Search | ActiveBlock::Login | ActiveBlock::Dialog
+                        );
+                        if is_input {
+                            handlers::handle_app(input, &mut app);
+                        } else {
                             let pop_result = app.pop_navigation_stack();
                             if pop_result.is_none() {
                                 break; // Exit application
@@ -163,20 +144,6 @@ fn main() -> Result<(), failure::Error> {
             }
         }
 
-        if is_first_render {
-            let cloud_music = app.cloud_music.to_owned().unwrap();
-            let playlists = cloud_music.user_playlists(&profile.userId.unwrap().to_string());
-            match playlists {
-                Ok(p) => {
-                    app.playlists = Some(p);
-                    app.selected_playlist_index = Some(0);
-                }
-                Err(e) => {
-                    app.handle_error(e);
-                }
-            };
-            is_first_render = false;
-        }
     }
     Ok(())
 }
@@ -2,12 +2,16 @@ use super::artist::Artist;
 use super::playlist::Track;
 use serde_derive::{Deserialize, Serialize};
 
+#[allow(non_snake_case)]
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Album {
     pub id: Option<i32>,
     pub name: Option<String>,
     pub size: Option<i32>,
     pub artist: Option<Artist>,
+    // release date, timestamp in milliseconds
+    pub publishTime: Option<u64>,
+    pub company: Option<String>,
 }
 
 #[allow(non_snake_case)]
@@ -1,11 +1,27 @@
 #[allow(non_snake_case)]
+use super::playlist::PlaylistTrack;
 use serde_derive::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[allow(non_snake_case)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct Artist {
     pub id: i32,
     pub name: String,
     pub alias: Option<Vec<String>>,
+    // only in artist detail
+    pub albumSize: Option<i32>,
+    pub musicSize: Option<i32>,
+    pub briefDesc: Option<String>,
+    pub followed: Option<bool>,
+}
+
+// artist info with top 50 songs
+#[allow(non_snake_case)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ArtistDetailRes {
+    pub artist: Option<Artist>,
+    pub hotSongs: Option<Vec<PlaylistTrack>>,
+    pub code: Option<i32>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -0,0 +1,58 @@
+use serde_derive::{Deserialize, Serialize};
+
+// resource has comments
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum CommentType {
+    Song,
+    Album,
+    Playlist,
+    DjProgram,
+}
+
+impl CommentType {
+    // thread id used by comment api
+    pub fn thread_id(self, id: i64) -> String {
+        let prefix = match self {
+            CommentType::Song => "R_SO_4_",
+            CommentType::Album => "R_AL_3_",
+            CommentType::Playlist => "A_PL_0_",
+            CommentType::DjProgram => "A_DJ_1_",
+        };
+        format!("{}{}", prefix, id)
+    }
+}
+
+#[allow(non_snake_case)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CommentRes {
+    pub comments: Option<Vec<Comment>>,
+    pub hotComments: Option<Vec<Comment>>,
+    pub total: Option<i32>,
+    pub more: Option<bool>,
+    pub code: Option<i32>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Comment {
+    pub commentId: i64,
+    pub user: CommentUser,
+    pub content: Option<String>,
+    pub time: u64,
+    pub likedCount: Option<i32>,
+    pub liked: Option<bool>,
+    pub beReplied: Option<Vec<BeReplied>>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CommentUser {
+    pub userId: Option<i64>,
+    pub nickname: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BeReplied {
+    pub user: CommentUser,
+    pub content: Option<String>,
+}
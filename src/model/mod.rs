@@ -1,7 +1,9 @@
 pub mod album;
 pub mod artist;
+pub mod comment;
 pub mod dj;
 pub mod lyric;
+pub mod personalized;
 pub mod playlist;
 pub mod search;
 pub mod song;
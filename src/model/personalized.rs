@@ -0,0 +1,47 @@
+use super::dj::DjProgram;
+use super::playlist::Track;
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PersonalizedPlaylistRes {
+    pub result: Option<Vec<PersonalizedPlaylist>>,
+    pub code: Option<i32>,
+}
+
+// recommend playlist, copywriter is the recommend reason
+#[allow(non_snake_case)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PersonalizedPlaylist {
+    pub id: Option<i64>,
+    pub name: Option<String>,
+    pub copywriter: Option<String>,
+    pub playCount: Option<f64>,
+    pub trackCount: Option<i32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PersonalizedNewSongRes {
+    pub result: Option<Vec<PersonalizedNewSong>>,
+    pub code: Option<i32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PersonalizedNewSong {
+    pub id: Option<i64>,
+    pub name: Option<String>,
+    pub song: Track,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PersonalizedProgramRes {
+    pub result: Option<Vec<PersonalizedProgram>>,
+    pub code: Option<i32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PersonalizedProgram {
+    pub id: Option<i64>,
+    pub name: Option<String>,
+    pub copywriter: Option<String>,
+    pub program: DjProgram,
+}
@@ -1,6 +1,7 @@
 use super::album::Album;
 use super::artist::Artist;
 use serde_derive::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PlaylistRes {
@@ -19,10 +20,21 @@ pub struct Playlist {
     pub description: Option<String>,
     pub privacy: Option<i32>,
     pub tags: Option<Vec<String>>,
+    // 5 means liked music of user
+    pub specialType: Option<i32>,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PlaylistCreateRes {
+    pub id: Option<i64>,
+    pub playlist: Option<Playlist>,
+    pub code: Option<i32>,
+}
+
+#[allow(non_snake_case)]
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Creator {
+    pub userId: Option<i64>,
     pub nickname: Option<String>,
     pub signature: Option<String>,
 }
@@ -42,6 +54,46 @@ pub struct PlaylistDetail {
     pub playCount: Option<i32>,
     pub creator: Option<Creator>,
     pub tracks: Vec<PlaylistTrack>,
+    pub trackIds: Option<Vec<TrackId>>,
+}
+
+// lr is last rank of track in toplist
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TrackId {
+    pub id: i64,
+    pub lr: Option<i32>,
+}
+
+// categories is group names by category id, such as language and style
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PlaylistCatalogueRes {
+    pub sub: Option<Vec<PlaylistCategory>>,
+    pub categories: Option<HashMap<String, String>>,
+    pub code: Option<i32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PlaylistCategory {
+    pub name: String,
+    pub category: Option<i32>,
+    pub hot: Option<bool>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ToplistRes {
+    pub list: Option<Vec<Toplist>>,
+    pub code: Option<i32>,
+}
+
+// official charts
+#[allow(non_snake_case)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Toplist {
+    pub id: Option<i64>,
+    pub name: Option<String>,
+    pub updateFrequency: Option<String>,
+    pub description: Option<String>,
+    pub trackCount: Option<i32>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -51,6 +103,11 @@ pub struct Track {
     pub id: Option<i64>,
     pub artists: Option<Vec<Artist>>,
     pub album: Option<Album>,
+    // why recommend this track, only in daily recommend
+    pub reason: Option<String>,
+    // last rank in toplist, starts from 0
+    #[serde(skip)]
+    pub last_rank: Option<i32>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -62,6 +119,20 @@ pub struct PlaylistTrack {
     pub al: Option<Album>,
 }
 
+impl From<PlaylistTrack> for Track {
+    fn from(t: PlaylistTrack) -> Track {
+        Track {
+            name: t.name,
+            fee: t.fee,
+            id: t.id,
+            artists: t.ar,
+            album: t.al,
+            reason: None,
+            last_rank: None,
+        }
+    }
+}
+
 impl PartialEq for Track {
     fn eq(&self, other: &Self) -> bool {
         self.id == other.id
@@ -70,12 +141,60 @@ impl PartialEq for Track {
 
 impl Eq for Track {}
 
+// play ranking of user, weekData or allData by type
+#[allow(non_snake_case)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PlayRecordRes {
+    pub weekData: Option<Vec<PlayRecord>>,
+    pub allData: Option<Vec<PlayRecord>>,
+    pub code: Option<i32>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PlayRecord {
+    pub playCount: Option<i32>,
+    pub score: Option<i32>,
+    pub song: PlaylistTrack,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LikedIdsRes {
+    pub ids: Option<Vec<i64>>,
+    pub code: Option<i32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RecommendSongsRes {
+    pub recommend: Option<Vec<Track>>,
+    pub code: Option<i32>,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PersonalFmRes {
     pub data: Vec<Track>,
     pub code: Option<i32>,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SimilarSongsRes {
+    pub songs: Option<Vec<Track>>,
+    pub code: Option<i32>,
+}
+
+// tracks of intelligence mode (心动模式)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct IntelligenceRes {
+    pub data: Option<Vec<IntelligenceTrack>>,
+    pub code: Option<i32>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct IntelligenceTrack {
+    pub songInfo: Option<PlaylistTrack>,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TopPlaylistRes {
     pub playlists: Vec<Playlist>,
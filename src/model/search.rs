@@ -3,6 +3,7 @@ use super::album::Album;
 use super::artist::Artist;
 use super::dj::DjRadio;
 use super::playlist::{Playlist, Track};
+use super::user::Profile;
 use serde_derive::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -35,28 +36,115 @@ pub struct SearchDjradioResult {
     pub code: i32,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SearchLyricResult {
+    pub result: SearchLyrics,
+    pub code: i32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SearchUserResult {
+    pub result: SearchUsers,
+    pub code: i32,
+}
+
+#[allow(non_snake_case)]
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SearchTracks {
     pub songs: Option<Vec<Track>>,
+    // total count of all pages
+    pub songCount: Option<i32>,
 }
 
+#[allow(non_snake_case)]
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SearchPlaylists {
     pub playlists: Option<Vec<Playlist>>,
+    pub playlistCount: Option<i32>,
 }
 
+#[allow(non_snake_case)]
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SearchArtists {
     pub artists: Option<Vec<Artist>>,
+    pub artistCount: Option<i32>,
 }
 
+#[allow(non_snake_case)]
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SearchAlbums {
     pub albums: Option<Vec<Album>>,
+    pub albumCount: Option<i32>,
 }
 
 #[allow(non_snake_case)]
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SearchDjRadios {
     pub djRadios: Option<Vec<DjRadio>>,
+    pub djRadiosCount: Option<i32>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SearchLyrics {
+    pub songs: Option<Vec<LyricTrack>>,
+    pub songCount: Option<i32>,
+}
+
+// track with lyric lines matching keyword, highlighted by <b></b>
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LyricTrack {
+    #[serde(flatten)]
+    pub track: Track,
+    pub lyrics: Option<Vec<String>>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SearchUsers {
+    pub userprofiles: Option<Vec<Profile>>,
+    pub userprofileCount: Option<i32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SearchSuggestRes {
+    pub result: Option<SearchSuggest>,
+    pub code: i32,
+}
+
+// suggestions of keyword, ordered by type
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SearchSuggest {
+    pub songs: Option<Vec<SuggestSong>>,
+    pub artists: Option<Vec<SuggestItem>>,
+    pub albums: Option<Vec<SuggestItem>>,
+    pub playlists: Option<Vec<SuggestItem>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SuggestSong {
+    pub name: Option<String>,
+    pub artists: Option<Vec<SuggestItem>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SuggestItem {
+    pub name: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SearchHotRes {
+    pub result: Option<SearchHots>,
+    pub code: i32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SearchHots {
+    pub hots: Option<Vec<SearchHot>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SearchHot {
+    // hot keyword
+    pub first: String,
 }
@@ -20,6 +20,7 @@ pub struct Profile {
     pub userId: Option<i32>,
     pub followeds: Option<i32>,
     pub follows: Option<i32>,
+    pub signature: Option<String>,
 }
 
 #[allow(non_snake_case)]
@@ -34,9 +35,30 @@ pub struct Login {
     pub account: Option<Account>,
     pub profile: Option<Profile>,
     pub code: Option<i32>,
+    pub msg: Option<String>,
+    pub message: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Status {
     pub profile: Profile,
 }
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QrKeyRes {
+    pub unikey: Option<String>,
+    pub code: i32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct QrCheckRes {
+    pub code: i32,
+    pub message: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CaptchaRes {
+    pub code: i32,
+    pub data: Option<bool>,
+    pub message: Option<String>,
+}
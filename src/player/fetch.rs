@@ -3,6 +3,7 @@ use futures::channel::oneshot::Sender;
 use reqwest::header::{CACHE_CONTROL, PRAGMA, HeaderMap, UPGRADE_INSECURE_REQUESTS, ACCEPT, ACCEPT_ENCODING, USER_AGENT};
 use reqwest::Method;
 use tempfile::NamedTempFile;
+use super::super::util::settings::stream_proxy;
 
 #[tokio::main]
 pub async fn fetch_data(url: &str, buffer: NamedTempFile, tx: Sender<String>) -> Result<(), failure::Error> {
@@ -20,10 +21,19 @@ pub async fn fetch_data(url: &str, buffer: NamedTempFile, tx: Sender<String>) ->
         USER_AGENT,
         "User-Agent: Mozilla/5.0 (X11; Linux x86_64; rv:65.0) Gecko/20100101 Firefox/65.0".parse().unwrap(),
     );
-    let client = reqwest::Client::builder()
-        // no need proxy but can add it in config
-        // .proxy(reqwest::Proxy::all("socks5://127.0.0.1:3333").expect("proxy error"))
-        .build().expect("builder error");
+    let client = reqwest::Client::builder();
+    // `stream_proxy` or `proxy` in config, or the proxy env vars
+    let client = match stream_proxy() {
+        Some(proxy) => match reqwest::Proxy::all(&proxy) {
+            Ok(proxy) => client.proxy(proxy),
+            Err(e) => {
+                error!("invalid stream proxy {}: {}", proxy, e);
+                client
+            }
+        },
+        None => client,
+    };
+    let client = client.build().expect("builder error");
     let builder = client.request(Method::GET, url).headers(headers);
     let mut res = builder.send().await?;
 
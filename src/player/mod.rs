@@ -10,6 +10,7 @@ mod track;
 // mod fetch_data;
 
 use player::Player;
+use std::path::PathBuf;
 // use sink::find;
 use std::sync::mpsc::Sender;
 
@@ -72,6 +73,15 @@ impl Nplayer {
         self.player.stop()
     }
 
+    // stop playing and forget current track
+    pub fn reset(&mut self) {
+        self.player.clear()
+    }
+
+    pub fn set_cache_dir(&mut self, cache_dir: Option<PathBuf>) {
+        self.player.cache_dir = cache_dir;
+    }
+
     pub fn get_position(&self) -> Option<u64> {
         match self.player.current.clone() {
             Some(current) => {
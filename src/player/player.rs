@@ -6,6 +6,7 @@ use std::time;
 
 use std::thread;
 use std::fs;
+use std::path::PathBuf;
 
 #[allow(unused)]
 pub enum PlayerState {
@@ -38,6 +39,8 @@ pub struct Player {
     pub sink: rodio::Sink,
     pub stream: rodio::OutputStream,
     pub stream_handle: rodio::OutputStreamHandle,
+    // dir to save downloading tracks
+    pub cache_dir: Option<PathBuf>,
 }
 
 // player
@@ -63,6 +66,7 @@ impl Player {
             sink,
             stream,
             stream_handle,
+            cache_dir: None,
             // endpoint: endpoint,
         }
     }
@@ -85,7 +89,16 @@ impl Player {
             None => {}
         }
 
-        let buffer = NamedTempFile::new().unwrap();
+        // download to cache dir, fallback to system temp dir
+        let cache_dir = self
+            .cache_dir
+            .as_ref()
+            .filter(|dir| fs::create_dir_all(dir).is_ok());
+        let buffer = match cache_dir {
+            Some(dir) => NamedTempFile::new_in(dir),
+            None => NamedTempFile::new(),
+        }
+        .unwrap();
         let path = buffer.path().to_string_lossy().to_string();
         // let pathbuf = PathBuf::from(path);
 
@@ -162,6 +175,15 @@ impl Player {
         self.sink.stop()
     }
 
+    // stop and remove current track
+    pub fn clear(&mut self) {
+        if let Some(track) = self.current.take() {
+            fs::remove_file(track.file()).ok();
+        }
+        self.start();
+        self.state = PlayerState::Stopped;
+    }
+
     #[allow(unused)]
     pub fn seek(&self, position_ms: u32) {
         // self.command(PlayerCommand::Seek(position_ms));
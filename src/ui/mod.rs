@@ -1,7 +1,12 @@
 pub mod circle;
 mod util;
 
-use super::app::{ActiveBlock, App, RepeatState, RouteId, RECOMMEND_OPTIONS};
+use super::api::qr_login_url;
+use super::app::{
+    ActiveBlock, App, ArtistTab, Dialog, DiscoverSection, HistoryTab, LoginMode, RepeatState, RouteId,
+    ALBUM_AREAS, ARTIST_AREAS, ARTIST_TYPES, MAX_SUGGESTIONS, RECOMMEND_OPTIONS,
+};
+use super::model::playlist::Track;
 use tui::backend::Backend;
 use tui::layout::{Alignment, Constraint, Direction, Layout, Rect};
 use tui::style::{Color, Modifier, Style};
@@ -11,8 +16,9 @@ use tui::widgets::{
 };
 use tui::Frame;
 use util::{
-    create_artist_string, create_datetime_string, create_tag_string, display_track_progress,
-    get_color, get_percentage_width, get_text_color
+    create_artist_string, create_datetime_string, create_lyric_snippet, create_qrcode_lines,
+    create_rank_change_string, create_tag_string,
+    display_track_progress, get_color, get_percentage_width, get_text_color
 };
 
 // table item for render
@@ -27,6 +33,15 @@ pub struct TableHeader<'a> {
     width: u16,
 }
 
+// show ♥ before name of liked track
+fn create_track_name(app: &App, track: &Track) -> String {
+    let name = track.name.to_owned().unwrap_or_default();
+    match track.id {
+        Some(id) if app.is_liked(id) => format!("♥ {}", name),
+        _ => name,
+    }
+}
+
 pub fn draw_main_layout<B>(f: &mut Frame<B>, app: &mut App)
 where
     B: Backend,
@@ -47,8 +62,24 @@ where
     // Search input and help
     draw_input_and_help_box(f, app, parent_layout[0]);
 
+    // suggestions dropdown under search input while typing
+    let suggestion = &app.search_suggestion;
+    let routes_chunk = if app.get_current_route().active_block == ActiveBlock::Search
+        && !suggestion.items.is_empty()
+    {
+        let height = suggestion.items.len().min(MAX_SUGGESTIONS) as u16 + 2;
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(height), Constraint::Min(1)].as_ref())
+            .split(parent_layout[1]);
+        draw_search_suggestion(f, app, chunks[0]);
+        chunks[1]
+    } else {
+        parent_layout[1]
+    };
+
     // Nested main block with potential routes
-    draw_routes(f, app, parent_layout[1]);
+    draw_routes(f, app, routes_chunk);
 
     // Currently playing
     draw_playing_block(f, app, parent_layout[2]);
@@ -72,17 +103,30 @@ where
         current_route.hovered_block == ActiveBlock::Search,
     );
 
-    let input: String = app.input.iter().collect();
-    Paragraph::new([Text::raw(&input)].iter())
-        .style(Style::default().fg(Color::Yellow))
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title("Search")
-                .title_style(get_color(highlight_state))
-                .border_style(get_color(highlight_state)),
-        )
-        .render(f, chunks[0]);
+    match &app.dialog {
+        // comments page draws its own dialog, others take place of search input
+        Some(dialog) if current_route.id != RouteId::Comments => {
+            draw_dialog(f, dialog, chunks[0]);
+        }
+        _ => {
+            let input: String = app.input.iter().collect();
+            // parse error of query shows in title
+            let (title, title_style) = match &app.search_error {
+                Some(e) => (format!("Search - {}", e), Style::default().fg(Color::Red)),
+                None => ("Search".to_string(), get_color(highlight_state)),
+            };
+            Paragraph::new([Text::raw(&input)].iter())
+                .style(Style::default().fg(Color::Yellow))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(&title)
+                        .title_style(title_style)
+                        .border_style(get_color(highlight_state)),
+                )
+                .render(f, chunks[0]);
+        }
+    }
 
     let block = Block::default()
         .title("Help")
@@ -96,6 +140,26 @@ where
         .render(f, chunks[1]);
 }
 
+fn draw_search_suggestion<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let items = app
+        .search_suggestion
+        .items
+        .iter()
+        .map(|(kind, keyword)| format!("[{}] {}", kind, keyword))
+        .collect::<Vec<String>>();
+    draw_selectable_list(
+        f,
+        layout_chunk,
+        "Suggestions (<Up>/<Down> select | <Enter> search)",
+        &items,
+        (true, false),
+        app.search_suggestion.selected_index,
+    );
+}
+
 pub fn draw_routes<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
 where
     B: Backend,
@@ -131,7 +195,7 @@ where
             draw_personal_fm(f, &app, chunks[1]);
         }
         RouteId::Artist => {
-            draw_artist_albums(f, app, chunks[1]);
+            draw_artist_detail(f, app, chunks[1]);
         }
         RouteId::AlbumTracks => {
             // artist's album list
@@ -157,6 +221,33 @@ where
         RouteId::Playing => {
             draw_playing_detail(f, app, chunks[1]);
         }
+        RouteId::Login => {
+            draw_login(f, app, chunks[1]);
+        }
+        RouteId::Profiles => {
+            draw_profiles(f, app, chunks[1]);
+        }
+        RouteId::PlaylistPicker => {
+            draw_playlist_picker(f, app, chunks[1]);
+        }
+        RouteId::Comments => {
+            draw_comments(f, app, chunks[1]);
+        }
+        RouteId::User => {
+            draw_user_page(f, app, chunks[1]);
+        }
+        RouteId::Discover => {
+            draw_discover(f, app, chunks[1]);
+        }
+        RouteId::History => {
+            draw_history(f, app, chunks[1]);
+        }
+        RouteId::Charts => {
+            draw_charts(f, app, chunks[1]);
+        }
+        RouteId::CategoryPicker => {
+            draw_category_picker(f, app, chunks[1]);
+        }
         RouteId::MyPlaylists => {
             // check track length for show
             if app.track_table.tracks.len() > 0 {
@@ -186,6 +277,7 @@ where
     };
 
     let repeat_text = match app.fm_state {
+        true if app.radio_seed.is_some() => "Radio",
         true => "FM",
         false => match app.repeat_state {
             RepeatState::Off => "Off",
@@ -205,7 +297,7 @@ where
 
     let (track_name, artist_name) = match &app.current_playing {
         Some(track) => (
-            track.name.to_owned().unwrap(),
+            create_track_name(app, track),
             match &track.artists {
                 Some(artists) => create_artist_string(&artists),
                 None => "Unknown".to_string(),
@@ -346,24 +438,45 @@ pub fn draw_track_table<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
 where
     B: Backend,
 {
-    let header = [
+    // show reason column for recommend tracks
+    let show_reason = app.track_table.tracks.iter().any(|t| t.reason.is_some());
+    // show rank change column for toplist
+    let show_rank = app.track_table.tracks.iter().any(|t| t.last_rank.is_some());
+    let column_width = match (show_reason, show_rank) {
+        (true, _) => 0.22,
+        (false, true) => 0.27,
+        (false, false) => 0.3,
+    };
+    let mut header = vec![
         TableHeader {
-            text: "ID",
+            text: if show_rank { "Rank" } else { "ID" },
             width: get_percentage_width(layout_chunk.width, 0.05),
         },
         TableHeader {
             text: "Title",
-            width: get_percentage_width(layout_chunk.width, 0.3),
+            width: get_percentage_width(layout_chunk.width, column_width),
         },
         TableHeader {
             text: "Artist",
-            width: get_percentage_width(layout_chunk.width, 0.3),
+            width: get_percentage_width(layout_chunk.width, column_width),
         },
         TableHeader {
             text: "Album",
-            width: get_percentage_width(layout_chunk.width, 0.3),
+            width: get_percentage_width(layout_chunk.width, column_width),
         },
     ];
+    if show_rank {
+        header.push(TableHeader {
+            text: "Change",
+            width: get_percentage_width(layout_chunk.width, 0.08),
+        });
+    }
+    if show_reason {
+        header.push(TableHeader {
+            text: "Reason",
+            width: get_percentage_width(layout_chunk.width, 0.24),
+        });
+    }
 
     let current_route = app.get_current_route();
     let highlight_state = (
@@ -378,17 +491,24 @@ where
         .iter()
         .map(|item| {
             num += 1;
+            let mut format = vec![
+                num.to_string(),
+                match item.fee.unwrap() {
+                    1 => format!("♚ {}", create_track_name(app, item)),
+                    _ => create_track_name(app, item),
+                },
+                create_artist_string(&item.artists.to_owned().unwrap()),
+                item.album.to_owned().unwrap().name.unwrap(),
+            ];
+            if show_rank {
+                format.push(create_rank_change_string(num - 1, item.last_rank));
+            }
+            if show_reason {
+                format.push(item.reason.to_owned().unwrap_or_default());
+            }
             TableItem {
                 id: item.id.as_ref().unwrap().to_string(),
-                format: vec![
-                    num.to_string(),
-                    match item.fee.unwrap() {
-                        1 => format!("♚ {}", item.name.as_ref().unwrap().to_string()),
-                        _ => item.name.as_ref().unwrap().to_string(),
-                    },
-                    create_artist_string(&item.artists.to_owned().unwrap()),
-                    item.album.to_owned().unwrap().name.unwrap(),
-                ],
+                format,
             }
         })
         .collect::<Vec<TableItem>>();
@@ -559,19 +679,95 @@ where
         current_route.active_block == ActiveBlock::PersonalFm,
         current_route.hovered_block == ActiveBlock::PersonalFm,
     );
-    let display_block = Block::default()
-        .title(&"PERSONAL FM")
-        .borders(Borders::ALL)
-        .title_style(get_color(highlight_state))
-        .border_style(get_color(highlight_state));
 
-    let text = vec![Text::raw("Your Personal FM")];
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(6), Constraint::Min(0)].as_ref())
+        .split(layout_chunk);
+
+    let fm_track_name = |track: &Track| {
+        let name = format!(
+            "{} - {}",
+            create_track_name(app, track),
+            create_artist_string(&track.artists.to_owned().unwrap_or_default())
+        );
+        match track.id {
+            Some(id) if app.fm.trashed.contains(&id) => format!("{} (trashed)", name),
+            _ => name,
+        }
+    };
+
+    let text = match &app.current_playing {
+        Some(track) if app.fm_state => {
+            let album = track
+                .album
+                .as_ref()
+                .and_then(|album| album.name.to_owned())
+                .unwrap_or_default();
+            let liked = match track.id {
+                Some(id) if app.is_liked(id) => "liked",
+                _ => "not liked",
+            };
+            vec![
+                Text::styled(
+                    format!("{}\n", fm_track_name(track)),
+                    Style::default().fg(Color::Yellow).modifier(Modifier::BOLD),
+                ),
+                Text::raw(format!("Album: {}\n", album)),
+                Text::raw(format!("State: {}\n", liked)),
+                Text::raw("<Ctrl+y> like | <Ctrl+t> trash | n next"),
+            ]
+        }
+        _ => vec![Text::raw("Your Personal FM")],
+    };
 
     Paragraph::new(text.iter())
         .style(Style::default().fg(Color::White))
-        .block(display_block)
+        .block(
+            Block::default()
+                .title("PERSONAL FM")
+                .borders(Borders::ALL)
+                .title_style(get_color(highlight_state))
+                .border_style(get_color(highlight_state)),
+        )
         .wrap(true)
-        .render(f, layout_chunk);
+        .render(f, chunks[0]);
+
+    let lists = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+        .split(chunks[1]);
+
+    // rest of current batch then prefetched one
+    let queue = &app.my_playlist;
+    let mut upcoming = match app.fm_state {
+        true => queue
+            .tracks
+            .iter()
+            .skip(queue.selected_index + 1)
+            .map(&fm_track_name)
+            .collect::<Vec<String>>(),
+        false => vec![],
+    };
+    upcoming.extend(
+        app.fm
+            .upcoming
+            .iter()
+            .map(|track| format!("{} (prefetched)", fm_track_name(track))),
+    );
+    if app.fm.is_prefetching() {
+        upcoming.push("loading next tracks...".to_string());
+    }
+    draw_selectable_list(f, lists[0], "Up Next", &upcoming, highlight_state, None);
+
+    let recent = app
+        .fm
+        .history
+        .iter()
+        .filter(|track| app.current_playing.as_ref() != Some(track))
+        .map(fm_track_name)
+        .collect::<Vec<String>>();
+    draw_selectable_list(f, lists[1], "Recent FM", &recent, highlight_state, None);
 }
 
 pub fn draw_search_results<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
@@ -592,11 +788,12 @@ where
     {
         let songs = match &app.search_results.tracks {
             Some(r) => r
+                .items
                 .iter()
                 .map(|item| {
                     format!(
                         "{} - {}",
-                        item.name.to_owned().unwrap(),
+                        create_track_name(app, item),
                         create_artist_string(&item.artists.to_owned().unwrap())
                     )
                 })
@@ -605,6 +802,7 @@ where
         };
         let playlists = match &app.search_results.playlists {
             Some(r) => r
+                .items
                 .iter()
                 .map(|item| {
                     format!(
@@ -617,11 +815,12 @@ where
             None => vec![],
         };
         let artists = match &app.search_results.artists {
-            Some(r) => r.iter().map(|item| item.to_owned().name).collect(),
+            Some(r) => r.items.iter().map(|item| item.to_owned().name).collect(),
             None => vec![],
         };
         let albums = match &app.search_results.albums {
             Some(r) => r
+                .items
                 .iter()
                 .map(|item| {
                     format!(
@@ -635,21 +834,68 @@ where
         };
         let djradios = match &app.search_results.djradios {
             Some(r) => r
+                .items
                 .iter()
                 .map(|item| format!("{}", item.name.to_owned()))
                 .collect(),
             None => vec![],
         };
+        let lyrics = match &app.search_results.lyrics {
+            Some(r) => r
+                .items
+                .iter()
+                .map(|item| {
+                    format!(
+                        "{} - {} | {}",
+                        create_track_name(app, &item.track),
+                        create_artist_string(&item.track.artists.to_owned().unwrap_or_default()),
+                        create_lyric_snippet(&item.lyrics.to_owned().unwrap_or_default())
+                    )
+                })
+                .collect(),
+            None => vec![],
+        };
+        let users = match &app.search_results.users {
+            Some(r) => r
+                .items
+                .iter()
+                .map(|item| match &item.signature {
+                    Some(signature) if !signature.is_empty() => format!(
+                        "{} - {}",
+                        item.nickname.to_owned().unwrap_or_default(),
+                        signature.replace('\n', " ")
+                    ),
+                    _ => item.nickname.to_owned().unwrap_or_default(),
+                })
+                .collect(),
+            None => vec![],
+        };
+
+        // total count after tab is fetched
+        let titles = app
+            .tabs
+            .titles
+            .iter()
+            .enumerate()
+            .map(|(i, title)| match app.search_results.counts(i) {
+                // only part of results matched artist or album
+                Some((matched, total)) if app.search_results.filter.is_filtered() => {
+                    format!("{} ({} of {})", title, matched, total)
+                }
+                Some((_, total)) => format!("{} ({})", title, total),
+                None => title.to_owned(),
+            })
+            .collect::<Vec<String>>();
 
         Tabs::default()
             .block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title("Search Result")
+                    .title(&format!("Search Result - {}", app.search_results.query))
                     .title_style(get_color(highlight_state))
                     .border_style(get_color(highlight_state)),
             )
-            .titles(&app.tabs.titles)
+            .titles(&titles)
             .select(app.tabs.index)
             .style(Style::default().fg(Color::Cyan))
             .highlight_style(Style::default().fg(Color::Yellow))
@@ -662,7 +908,10 @@ where
                 "Songs",
                 &songs,
                 highlight_state,
-                Some(app.search_results.selected_tracks_index),
+                app.search_results
+                    .tracks
+                    .as_ref()
+                    .map(|l| l.selected_index),
             ),
             1 => draw_selectable_list(
                 f,
@@ -670,7 +919,10 @@ where
                 "Artists",
                 &artists,
                 highlight_state,
-                Some(app.search_results.selected_artists_index),
+                app.search_results
+                    .artists
+                    .as_ref()
+                    .map(|l| l.selected_index),
             ),
             2 => draw_selectable_list(
                 f,
@@ -678,7 +930,10 @@ where
                 "Albums",
                 &albums,
                 highlight_state,
-                Some(app.search_results.selected_albums_index),
+                app.search_results
+                    .albums
+                    .as_ref()
+                    .map(|l| l.selected_index),
             ),
             3 => draw_selectable_list(
                 f,
@@ -686,7 +941,10 @@ where
                 "Playlists",
                 &playlists,
                 highlight_state,
-                Some(app.search_results.selected_playlists_index),
+                app.search_results
+                    .playlists
+                    .as_ref()
+                    .map(|l| l.selected_index),
             ),
             4 => draw_selectable_list(
                 f,
@@ -694,7 +952,32 @@ where
                 "djradio",
                 &djradios,
                 highlight_state,
-                Some(app.search_results.selected_djradio_index),
+                app.search_results
+                    .djradios
+                    .as_ref()
+                    .map(|l| l.selected_index),
+            ),
+            5 => draw_selectable_list(
+                f,
+                chunks[1],
+                "Lyrics",
+                &lyrics,
+                highlight_state,
+                app.search_results
+                    .lyrics
+                    .as_ref()
+                    .map(|l| l.selected_index),
+            ),
+            6 => draw_selectable_list(
+                f,
+                chunks[1],
+                "Users",
+                &users,
+                highlight_state,
+                app.search_results
+                    .users
+                    .as_ref()
+                    .map(|l| l.selected_index),
             ),
 
             _ => {}
@@ -737,13 +1020,52 @@ where
         ],
         vec!["Enter hover mode", "<Esc>", "General"],
         vec!["Enter active mode", "<Enter>", "General"],
-        vec!["Like current playing track", "<Ctrl+y>", "General"],
+        vec!["Like/unlike current playing track", "<Ctrl+y>", "General"],
         vec!["Dislike current playing track", "<Ctrl+d>", "General"],
-        vec!["move track to trash", "<Ctrl+t>", "FM block"],
+        vec!["Switch account profile", "<Alt+u>", "General"],
+        vec!["move track to trash and jump to next tracks", "<Ctrl+t>", "FM block"],
+        vec![
+            "Add track to playlist",
+            "<Alt+a>",
+            "Track table | Album | Search result | Artist | Discover | History",
+        ],
+        vec!["Show comments of selected item", "c", "Track table | Album | Playlist | Dj program | Artist"],
+        vec!["Show comments of current playlist or album", "C", "Track table"],
+        vec!["Switch hot/latest comments", "<Tab>", "Comments"],
+        vec!["Write comment", "w", "Comments"],
+        vec!["Reply to selected comment", "<Enter>", "Comments"],
+        vec!["Like/unlike selected comment", "y", "Comments"],
+        vec!["Show user page of creator or author", "u", "Playlist | User | Comments"],
+        vec!["Remove track from own playlist", "<Alt+d>", "Track table"],
+        vec!["Create public/private playlist", "<Alt+n>/<Alt+p>", "My playlists"],
+        vec!["Rename own playlist", "<Alt+e>", "My playlists"],
+        vec!["Delete own playlist", "<Alt+d>", "My playlists"],
+        vec![
+            "Start song radio from selected track",
+            "<Alt+r>",
+            "Track table | Album | Search result | History",
+        ],
+        vec!["Switch discover section", "<Tab>", "Discover"],
+        vec!["Show hot playlists", "m", "Discover"],
+        vec!["Choose playlist category", "g", "Playlist block"],
+        vec!["Switch hot/new playlists", "o", "Playlist block"],
+        vec!["Switch album area", "<Tab>", "Album list"],
+        vec!["Switch artist area", "e", "Artist list"],
+        vec!["Switch artist type", "t", "Artist list"],
+        vec!["Next/previous initial letter", "] | [", "Artist list"],
+        vec!["Switch recent/weekly/all time", "<Tab>", "History"],
+        vec!["Switch songs/albums/similar artists", "<Tab>", "Artist"],
+        vec!["Follow artist", "<Alt+s>", "Artist"],
+        vec!["Unfollow artist", "<Alt+d>", "Artist"],
+        vec!["Refresh login QR code", "r", "Login block"],
+        vec!["Switch login mode", "<Left/Right Arrow Key>", "Login block"],
+        vec!["Switch login input", "<Tab>", "Login block"],
+        vec!["Send SMS verification code", "<Ctrl+s>", "Login block"],
         vec!["Delete entire input", "<Ctrl+u>", "Search input"],
         vec!["Search with input text", "<Enter>", "Search input"],
         vec!["Jump to start of input", "<Ctrl+a>", "Search input"],
         vec!["Jump to end of input", "<Ctrl+e>", "Search input"],
+        vec!["Select suggestion", "<Up> | <Down>", "Search input"],
         vec![
             "Subscribe current hover playlist",
             "<Alt+s>",
@@ -754,12 +1076,9 @@ where
             "<Alt+d>",
             "Playlist block",
         ],
-        vec!["Jump to next page", "<Ctrl+f>", "Search result | top list"],
-        vec![
-            "Jump to previous page",
-            "<Ctrl+b>",
-            "Search result | top list",
-        ],
+        vec!["Jump to next page", "<Ctrl+f>", "top list"],
+        vec!["Jump to previous page", "<Ctrl+b>", "top list"],
+        vec!["Load more results at end of list", "<Down>", "Search result"],
     ];
 
     let rows = help_docs
@@ -873,7 +1192,7 @@ struct ListUI {
     title: String,
 }
 
-pub fn draw_artist_albums<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+pub fn draw_artist_detail<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
 where
     B: Backend,
 {
@@ -883,59 +1202,195 @@ where
         current_route.hovered_block == ActiveBlock::Artist,
     );
 
-    let header = [
-        TableHeader {
-            text: "",
-            width: get_percentage_width(layout_chunk.width, 0.05),
-        },
-        TableHeader {
-            text: "Album Name",
-            width: get_percentage_width(layout_chunk.width, 0.3),
-        },
-        TableHeader {
-            text: "Artist",
-            width: get_percentage_width(layout_chunk.width, 0.3),
-        },
+    let artist_detail = match &app.artist_detail {
+        Some(artist_detail) => artist_detail,
+        None => return,
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(6),
+                Constraint::Length(3),
+                Constraint::Min(0),
+            ]
+            .as_ref(),
+        )
+        .split(layout_chunk);
+
+    let artist = &artist_detail.artist;
+    let title = match &artist.alias {
+        Some(alias) if !alias.is_empty() => format!("{} ({})", artist.name, alias.join(" / ")),
+        _ => artist.name.to_owned(),
+    };
+    let text = [
+        Text::styled(
+            format!(
+                "{} albums | {} songs{}\n",
+                artist.albumSize.unwrap_or(0),
+                artist.musicSize.unwrap_or(0),
+                if artist.followed == Some(true) {
+                    " | followed"
+                } else {
+                    ""
+                }
+            ),
+            Style::default().fg(Color::Cyan),
+        ),
+        Text::raw(artist.briefDesc.to_owned().unwrap_or_default()),
     ];
+    Paragraph::new(text.iter())
+        .wrap(true)
+        .style(Style::default().fg(Color::White))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(&title)
+                .title_style(get_color(highlight_state))
+                .border_style(get_color(highlight_state)),
+        )
+        .render(f, chunks[0]);
 
-    let mut num = 0;
-    let album_ui = match &app.artist_albums {
-        Some(album_list) => Some(ListUI {
-            items: album_list
+    Tabs::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title_style(get_color(highlight_state))
+                .border_style(get_color(highlight_state)),
+        )
+        .titles(&["Top 50 Songs", "Albums", "Similar Artists"])
+        .select(match artist_detail.tab {
+            ArtistTab::Songs => 0,
+            ArtistTab::Albums => 1,
+            ArtistTab::Similar => 2,
+        })
+        .style(Style::default().fg(Color::Cyan))
+        .highlight_style(Style::default().fg(Color::Yellow))
+        .render(f, chunks[1]);
+
+    match artist_detail.tab {
+        ArtistTab::Songs => {
+            let header = [
+                TableHeader {
+                    text: "",
+                    width: get_percentage_width(layout_chunk.width, 0.05),
+                },
+                TableHeader {
+                    text: "Title",
+                    width: get_percentage_width(layout_chunk.width, 0.5),
+                },
+                TableHeader {
+                    text: "Album",
+                    width: get_percentage_width(layout_chunk.width, 0.4),
+                },
+            ];
+            let items = artist_detail
+                .songs
+                .iter()
+                .enumerate()
+                .map(|(i, track)| TableItem {
+                    id: track.id.unwrap_or(0).to_string(),
+                    format: vec![
+                        (i + 1).to_string(),
+                        create_track_name(app, track),
+                        track
+                            .album
+                            .as_ref()
+                            .and_then(|a| a.name.to_owned())
+                            .unwrap_or_default(),
+                    ],
+                })
+                .collect::<Vec<TableItem>>();
+            draw_table(
+                f,
+                app,
+                chunks[2],
+                ("", &header),
+                &items,
+                artist_detail.selected_song_index,
+                highlight_state,
+            );
+        }
+        ArtistTab::Albums => {
+            let header = [
+                TableHeader {
+                    text: "",
+                    width: get_percentage_width(layout_chunk.width, 0.05),
+                },
+                TableHeader {
+                    text: "Album Name",
+                    width: get_percentage_width(layout_chunk.width, 0.5),
+                },
+                TableHeader {
+                    text: "Tracks",
+                    width: get_percentage_width(layout_chunk.width, 0.2),
+                },
+            ];
+            let items = artist_detail
                 .albums
                 .iter()
-                .map(|item| {
-                    num += 1;
-                    TableItem {
-                        id: item.id.clone().unwrap_or_else(|| 0).to_string(),
-                        format: vec![
-                            num.to_string(),
-                            item.to_owned().name.unwrap().to_string(),
-                            item.artist.to_owned().unwrap().name.to_string(),
-                        ],
-                    }
+                .enumerate()
+                .map(|(i, album)| TableItem {
+                    id: album.id.unwrap_or(0).to_string(),
+                    format: vec![
+                        (i + 1).to_string(),
+                        album.name.to_owned().unwrap_or_default(),
+                        album.size.unwrap_or(0).to_string(),
+                    ],
                 })
-                .collect::<Vec<TableItem>>(),
-            title: format!("Albums",),
-            selected_index: album_list.selected_index,
-        }),
-        None => None,
-    };
-
-    if let Some(album_ui) = album_ui {
-        draw_table(
-            f,
-            &app,
-            layout_chunk,
-            (&album_ui.title, &header),
-            &album_ui.items,
-            album_ui.selected_index,
-            highlight_state,
-        );
-    };
+                .collect::<Vec<TableItem>>();
+            draw_table(
+                f,
+                app,
+                chunks[2],
+                ("", &header),
+                &items,
+                artist_detail.selected_album_index,
+                highlight_state,
+            );
+        }
+        ArtistTab::Similar => {
+            let header = [
+                TableHeader {
+                    text: "",
+                    width: get_percentage_width(layout_chunk.width, 0.05),
+                },
+                TableHeader {
+                    text: "Artist",
+                    width: get_percentage_width(layout_chunk.width, 0.5),
+                },
+                TableHeader {
+                    text: "Alias",
+                    width: get_percentage_width(layout_chunk.width, 0.4),
+                },
+            ];
+            let items = artist_detail
+                .similar
+                .iter()
+                .enumerate()
+                .map(|(i, artist)| TableItem {
+                    id: artist.id.to_string(),
+                    format: vec![
+                        (i + 1).to_string(),
+                        artist.name.to_owned(),
+                        artist.alias.to_owned().unwrap_or_default().join(" / "),
+                    ],
+                })
+                .collect::<Vec<TableItem>>();
+            draw_table(
+                f,
+                app,
+                chunks[2],
+                ("", &header),
+                &items,
+                artist_detail.selected_similar_index,
+                highlight_state,
+            );
+        }
+    }
 }
 
-// dtaw album table
 pub fn draw_album_table<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
 where
     B: Backend,
@@ -967,7 +1422,7 @@ where
                     num += 1;
                     TableItem {
                         id: item.id.clone().unwrap_or_else(|| 0).to_string(),
-                        format: vec![num.to_string(), item.to_owned().name.unwrap().to_string()],
+                        format: vec![num.to_string(), create_track_name(app, item)],
                     }
                 })
                 .collect::<Vec<TableItem>>(),
@@ -1055,7 +1510,11 @@ where
                     }
                 })
                 .collect::<Vec<TableItem>>(),
-            title: format!("Discover Playlists",),
+            title: format!(
+                "Playlists - {} ({})",
+                playlist.category,
+                if playlist.hot { "hot" } else { "new" }
+            ),
             selected_index: playlist.selected_index,
         }),
         None => None,
@@ -1084,6 +1543,11 @@ where
         current_route.hovered_block == ActiveBlock::AlbumList,
     );
 
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)].as_ref())
+        .split(layout_chunk);
+
     let header = [
         TableHeader {
             text: "",
@@ -1091,16 +1555,20 @@ where
         },
         TableHeader {
             text: "Album Name",
-            width: get_percentage_width(layout_chunk.width, 0.3),
+            width: get_percentage_width(layout_chunk.width, 0.35),
         },
         TableHeader {
             text: "Artist",
             width: get_percentage_width(layout_chunk.width, 0.3),
         },
+        TableHeader {
+            text: "Release Date",
+            width: get_percentage_width(layout_chunk.width, 0.2),
+        },
     ];
 
     let mut num = match app.album_list.to_owned() {
-        Some(albumlist) => albumlist.selected_page * (app.block_height - 4),
+        Some(albumlist) => albumlist.selected_page * app.album_page_size(),
         None => 0,
     };
 
@@ -1112,26 +1580,53 @@ where
                 .map(|item| {
                     num += 1;
                     TableItem {
-                        id: item.id.clone().unwrap_or_else(|| 0).to_string(),
+                        id: item.id.unwrap_or(0).to_string(),
                         format: vec![
                             num.to_string(),
-                            item.to_owned().name.unwrap().to_string(),
-                            item.artist.to_owned().unwrap().name.to_string(),
+                            item.name.to_owned().unwrap_or_default(),
+                            item.artist
+                                .as_ref()
+                                .map(|artist| artist.name.to_owned())
+                                .unwrap_or_default(),
+                            item.publishTime
+                                .map(create_datetime_string)
+                                .unwrap_or_default(),
                         ],
                     }
                 })
                 .collect::<Vec<TableItem>>(),
-            title: format!("Discover Albums",),
+            title: "New Releases".to_string(),
             selected_index: album_list.selected_index,
         }),
         None => None,
     };
 
+    if let Some(album_list) = &app.album_list {
+        Tabs::default()
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Area")
+                    .title_style(get_color(highlight_state))
+                    .border_style(get_color(highlight_state)),
+            )
+            .titles(
+                &ALBUM_AREAS
+                    .iter()
+                    .map(|(name, _)| *name)
+                    .collect::<Vec<&str>>(),
+            )
+            .select(album_list.area)
+            .style(Style::default().fg(Color::Cyan))
+            .highlight_style(Style::default().fg(Color::Yellow))
+            .render(f, chunks[0]);
+    }
+
     if let Some(album_ui) = album_ui {
         draw_table(
             f,
-            &app,
-            layout_chunk,
+            app,
+            chunks[1],
             (&album_ui.title, &header),
             &album_ui.items,
             album_ui.selected_index,
@@ -1179,7 +1674,19 @@ where
                     }
                 })
                 .collect::<Vec<TableItem>>(),
-            title: format!("Discover Artists",),
+            title: if artist_list.filter.is_default() {
+                "Hot Artists".to_string()
+            } else {
+                format!(
+                    "Artists - area: {} | type: {} | initial: {}",
+                    ARTIST_AREAS[artist_list.filter.area].0,
+                    ARTIST_TYPES[artist_list.filter.kind].0,
+                    artist_list
+                        .filter
+                        .initial
+                        .map_or("All".to_string(), |c| c.to_string())
+                )
+            },
             selected_index: artist_list.selected_index,
         }),
         None => None,
@@ -1198,29 +1705,744 @@ where
     };
 }
 
-pub fn draw_djradio_list<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+pub fn draw_user_page<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
 where
     B: Backend,
 {
     let current_route = app.get_current_route();
     let highlight_state = (
-        current_route.active_block == ActiveBlock::DjRadio,
-        current_route.hovered_block == ActiveBlock::DjRadio,
+        current_route.active_block == ActiveBlock::User,
+        current_route.hovered_block == ActiveBlock::User,
     );
 
-    let header = [
-        TableHeader {
-            text: "",
-            width: get_percentage_width(layout_chunk.width, 0.05),
-        },
-        TableHeader {
-            text: "DjRadio Name",
-            width: get_percentage_width(layout_chunk.width, 0.3),
-        },
-    ];
+    let user_page = match &app.user_page {
+        Some(user_page) => user_page,
+        None => return,
+    };
 
-    let mut num = match app.djradio_list.to_owned() {
-        Some(djradio_list) => djradio_list.selected_page * (app.block_height - 4),
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(5), Constraint::Min(0)].as_ref())
+        .split(layout_chunk);
+
+    let user = &user_page.user;
+    let (nickname, signature, follows, followeds) = match &user.profile {
+        Some(profile) => (
+            profile.nickname.to_owned().unwrap_or_default(),
+            profile.signature.to_owned().unwrap_or_default(),
+            profile.follows.unwrap_or(0),
+            profile.followeds.unwrap_or(0),
+        ),
+        None => (String::new(), String::new(), 0, 0),
+    };
+    let text = [
+        Text::styled(
+            format!(
+                "Lv.{} | {} songs listened | {} days | {} follows | {} followers\n",
+                user.level.unwrap_or(0),
+                user.listenSongs.unwrap_or(0),
+                user.createDays.unwrap_or(0),
+                follows,
+                followeds
+            ),
+            Style::default().fg(Color::Cyan),
+        ),
+        Text::raw(signature),
+    ];
+    Paragraph::new(text.iter())
+        .wrap(true)
+        .style(Style::default().fg(Color::White))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(&nickname)
+                .title_style(get_color(highlight_state))
+                .border_style(get_color(highlight_state)),
+        )
+        .render(f, chunks[0]);
+
+    let header = [
+        TableHeader {
+            text: "Playlist",
+            width: get_percentage_width(layout_chunk.width, 0.5),
+        },
+        TableHeader {
+            text: "Creator",
+            width: get_percentage_width(layout_chunk.width, 0.2),
+        },
+        TableHeader {
+            text: "Tracks",
+            width: get_percentage_width(layout_chunk.width, 0.1),
+        },
+        TableHeader {
+            text: "Type",
+            width: get_percentage_width(layout_chunk.width, 0.2),
+        },
+    ];
+
+    let items = user_page
+        .playlists
+        .iter()
+        .map(|playlist| TableItem {
+            id: playlist.id.unwrap_or(0).to_string(),
+            format: vec![
+                playlist.name.to_owned().unwrap_or_default(),
+                playlist
+                    .creator
+                    .as_ref()
+                    .and_then(|c| c.nickname.to_owned())
+                    .unwrap_or_default(),
+                playlist.trackCount.unwrap_or(0).to_string(),
+                match playlist.userId {
+                    Some(id) if id == user_page.user_id => "created",
+                    _ => "subscribed",
+                }
+                .to_string(),
+            ],
+        })
+        .collect::<Vec<TableItem>>();
+
+    draw_table(
+        f,
+        app,
+        chunks[1],
+        ("Playlists", &header),
+        &items,
+        user_page.selected_index,
+        highlight_state,
+    );
+}
+
+pub fn draw_discover<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let current_route = app.get_current_route();
+    let discover = match &app.discover {
+        Some(discover) => discover,
+        None => return,
+    };
+    // only highlight selected section
+    let highlight_state = |section: DiscoverSection| {
+        (
+            current_route.active_block == ActiveBlock::Discover && discover.section == section,
+            current_route.hovered_block == ActiveBlock::Discover && discover.section == section,
+        )
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Percentage(40),
+                Constraint::Percentage(30),
+                Constraint::Percentage(30),
+            ]
+            .as_ref(),
+        )
+        .split(layout_chunk);
+
+    let playlist_header = [
+        TableHeader {
+            text: "Playlist",
+            width: get_percentage_width(layout_chunk.width, 0.4),
+        },
+        TableHeader {
+            text: "Tracks",
+            width: get_percentage_width(layout_chunk.width, 0.1),
+        },
+        TableHeader {
+            text: "Reason",
+            width: get_percentage_width(layout_chunk.width, 0.45),
+        },
+    ];
+    let playlist_items = discover
+        .playlists
+        .iter()
+        .map(|playlist| TableItem {
+            id: playlist.id.unwrap_or(0).to_string(),
+            format: vec![
+                playlist.name.to_owned().unwrap_or_default(),
+                playlist.trackCount.unwrap_or(0).to_string(),
+                playlist.copywriter.to_owned().unwrap_or_default(),
+            ],
+        })
+        .collect::<Vec<TableItem>>();
+    draw_table(
+        f,
+        app,
+        chunks[0],
+        ("Recommend Playlists", &playlist_header),
+        &playlist_items,
+        discover.selected_playlist_index,
+        highlight_state(DiscoverSection::Playlists),
+    );
+
+    let song_header = [
+        TableHeader {
+            text: "Title",
+            width: get_percentage_width(layout_chunk.width, 0.4),
+        },
+        TableHeader {
+            text: "Artist",
+            width: get_percentage_width(layout_chunk.width, 0.3),
+        },
+        TableHeader {
+            text: "Album",
+            width: get_percentage_width(layout_chunk.width, 0.25),
+        },
+    ];
+    let song_items = discover
+        .songs
+        .iter()
+        .map(|track| TableItem {
+            id: track.id.unwrap_or(0).to_string(),
+            format: vec![
+                create_track_name(app, track),
+                create_artist_string(&track.artists.to_owned().unwrap_or_default()),
+                track
+                    .album
+                    .as_ref()
+                    .and_then(|a| a.name.to_owned())
+                    .unwrap_or_default(),
+            ],
+        })
+        .collect::<Vec<TableItem>>();
+    draw_table(
+        f,
+        app,
+        chunks[1],
+        ("New Songs", &song_header),
+        &song_items,
+        discover.selected_song_index,
+        highlight_state(DiscoverSection::Songs),
+    );
+
+    let program_header = [
+        TableHeader {
+            text: "Dj Program Name",
+            width: get_percentage_width(layout_chunk.width, 0.4),
+        },
+        TableHeader {
+            text: "Dj Radio",
+            width: get_percentage_width(layout_chunk.width, 0.3),
+        },
+        TableHeader {
+            text: "Reason",
+            width: get_percentage_width(layout_chunk.width, 0.25),
+        },
+    ];
+    let program_items = discover
+        .programs
+        .iter()
+        .map(|item| TableItem {
+            id: item.program.mainSong.id.to_string(),
+            format: vec![
+                item.program.mainSong.name.to_string(),
+                item.program.radio.name.to_string(),
+                item.copywriter.to_owned().unwrap_or_default(),
+            ],
+        })
+        .collect::<Vec<TableItem>>();
+    draw_table(
+        f,
+        app,
+        chunks[2],
+        ("Recommend Dj Programs", &program_header),
+        &program_items,
+        discover.selected_program_index,
+        highlight_state(DiscoverSection::Programs),
+    );
+}
+
+pub fn draw_charts<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::Charts,
+        current_route.hovered_block == ActiveBlock::Charts,
+    );
+
+    let chart_list = match &app.chart_list {
+        Some(chart_list) => chart_list,
+        None => return,
+    };
+
+    let header = [
+        TableHeader {
+            text: "Chart",
+            width: get_percentage_width(layout_chunk.width, 0.2),
+        },
+        TableHeader {
+            text: "Update",
+            width: get_percentage_width(layout_chunk.width, 0.15),
+        },
+        TableHeader {
+            text: "Description",
+            width: get_percentage_width(layout_chunk.width, 0.6),
+        },
+    ];
+
+    let items = chart_list
+        .charts
+        .iter()
+        .map(|chart| TableItem {
+            id: chart.id.unwrap_or(0).to_string(),
+            format: vec![
+                chart.name.to_owned().unwrap_or_default(),
+                chart.updateFrequency.to_owned().unwrap_or_default(),
+                chart
+                    .description
+                    .to_owned()
+                    .unwrap_or_default()
+                    .replace('\n', " "),
+            ],
+        })
+        .collect::<Vec<TableItem>>();
+
+    draw_table(
+        f,
+        app,
+        layout_chunk,
+        ("Charts", &header),
+        &items,
+        chart_list.selected_index,
+        highlight_state,
+    );
+}
+
+pub fn draw_history<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::History,
+        current_route.hovered_block == ActiveBlock::History,
+    );
+
+    let history = match &app.history {
+        Some(history) => history,
+        None => return,
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)].as_ref())
+        .split(layout_chunk);
+
+    Tabs::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("History")
+                .title_style(get_color(highlight_state))
+                .border_style(get_color(highlight_state)),
+        )
+        .titles(&["Recently Played", "Last Week", "All Time"])
+        .select(match history.tab {
+            HistoryTab::Recent => 0,
+            HistoryTab::Week => 1,
+            HistoryTab::All => 2,
+        })
+        .style(Style::default().fg(Color::Cyan))
+        .highlight_style(Style::default().fg(Color::Yellow))
+        .render(f, chunks[0]);
+
+    let header = [
+        TableHeader {
+            text: "",
+            width: get_percentage_width(layout_chunk.width, 0.05),
+        },
+        TableHeader {
+            text: "Title",
+            width: get_percentage_width(layout_chunk.width, 0.35),
+        },
+        TableHeader {
+            text: "Artist",
+            width: get_percentage_width(layout_chunk.width, 0.25),
+        },
+        TableHeader {
+            text: "Album",
+            width: get_percentage_width(layout_chunk.width, 0.25),
+        },
+        TableHeader {
+            text: "Plays",
+            width: get_percentage_width(layout_chunk.width, 0.1),
+        },
+    ];
+
+    let items = history
+        .tracks
+        .iter()
+        .enumerate()
+        .map(|(i, track)| TableItem {
+            id: track.id.unwrap_or(0).to_string(),
+            format: vec![
+                (i + 1).to_string(),
+                create_track_name(app, track),
+                create_artist_string(&track.artists.to_owned().unwrap_or_default()),
+                track
+                    .album
+                    .as_ref()
+                    .and_then(|a| a.name.to_owned())
+                    .unwrap_or_default(),
+                history
+                    .play_counts
+                    .get(i)
+                    .map(|count| count.to_string())
+                    .unwrap_or_default(),
+            ],
+        })
+        .collect::<Vec<TableItem>>();
+
+    draw_table(
+        f,
+        app,
+        chunks[1],
+        ("", &header),
+        &items,
+        history.selected_index,
+        highlight_state,
+    );
+}
+
+pub fn draw_comments<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::Comments,
+        current_route.hovered_block == ActiveBlock::Comments,
+    );
+
+    let list = match &app.comment_list {
+        Some(list) => list,
+        None => return,
+    };
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Min(5),
+                Constraint::Length(7),
+            ]
+            .as_ref(),
+        )
+        .split(layout_chunk);
+
+    let tab_titles = [
+        "Hot".to_string(),
+        format!("Latest ({})", list.total),
+    ];
+    Tabs::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(&format!("Comments of {}", list.title))
+                .title_style(get_color(highlight_state))
+                .border_style(get_color(highlight_state)),
+        )
+        .titles(&tab_titles)
+        .select(if list.hot { 0 } else { 1 })
+        .style(Style::default().fg(Color::Cyan))
+        .highlight_style(Style::default().fg(Color::Yellow))
+        .render(f, chunks[0]);
+
+    let header = [
+        TableHeader {
+            text: "User",
+            width: get_percentage_width(layout_chunk.width, 0.2),
+        },
+        TableHeader {
+            text: "Comment",
+            width: get_percentage_width(layout_chunk.width, 0.55),
+        },
+        TableHeader {
+            text: "Likes",
+            width: get_percentage_width(layout_chunk.width, 0.1),
+        },
+        TableHeader {
+            text: "Time",
+            width: get_percentage_width(layout_chunk.width, 0.15),
+        },
+    ];
+
+    let items = list
+        .comments
+        .iter()
+        .map(|comment| TableItem {
+            id: comment.commentId.to_string(),
+            format: vec![
+                comment.user.nickname.to_owned().unwrap_or_default(),
+                comment.content.to_owned().unwrap_or_default().replace('\n', " "),
+                // mark liked comment
+                match comment.liked {
+                    Some(true) => format!("♥ {}", comment.likedCount.unwrap_or(0)),
+                    _ => comment.likedCount.unwrap_or(0).to_string(),
+                },
+                create_datetime_string(comment.time),
+            ],
+        })
+        .collect::<Vec<TableItem>>();
+
+    draw_table(
+        f,
+        app,
+        chunks[1],
+        (&format!("Page {}", list.selected_page + 1), &header),
+        &items,
+        list.selected_index,
+        highlight_state,
+    );
+
+    match &app.dialog {
+        Some(dialog) => draw_dialog(f, dialog, chunks[2]),
+        None => {
+            // full content of selected comment
+            let mut text = vec![];
+            if let Some(comment) = list.comments.get(list.selected_index) {
+                text.push(Text::raw(comment.content.to_owned().unwrap_or_default()));
+                if let Some(replied) = comment.beReplied.as_ref().and_then(|r| r.first()) {
+                    text.push(Text::styled(
+                        format!(
+                            "\n> @{}: {}",
+                            replied.user.nickname.to_owned().unwrap_or_default(),
+                            replied.content.to_owned().unwrap_or_default()
+                        ),
+                        Style::default().fg(Color::Gray),
+                    ));
+                }
+            }
+            Paragraph::new(text.iter())
+                .wrap(true)
+                .style(Style::default().fg(Color::White))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title("<Tab> hot/latest | <Enter> reply | w write | y like")
+                        .title_style(get_color(highlight_state))
+                        .border_style(get_color(highlight_state)),
+                )
+                .render(f, chunks[2]);
+        }
+    }
+}
+
+// input dialog, submit with enter and cancel with esc
+fn draw_dialog<B>(f: &mut Frame<B>, dialog: &Dialog, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let text = [Text::styled(
+        format!("{}_", dialog.input),
+        Style::default().fg(Color::Yellow),
+    )];
+    Paragraph::new(text.iter())
+        .wrap(true)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(&format!("{} (<Enter> send | <Esc> cancel)", dialog.title))
+                .title_style(get_color((true, false)))
+                .border_style(get_color((true, false))),
+        )
+        .render(f, layout_chunk);
+}
+
+pub fn draw_category_picker<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::CategoryPicker,
+        current_route.hovered_block == ActiveBlock::CategoryPicker,
+    );
+
+    let header = [
+        TableHeader {
+            text: "Group",
+            width: get_percentage_width(layout_chunk.width, 0.3),
+        },
+        TableHeader {
+            text: "Category",
+            width: get_percentage_width(layout_chunk.width, 0.5),
+        },
+    ];
+
+    if let Some(picker) = &app.category_picker {
+        let items = picker
+            .categories
+            .iter()
+            .map(|category| TableItem {
+                id: category.name.to_owned(),
+                format: vec![
+                    category
+                        .category
+                        .and_then(|id| picker.groups.get(&id.to_string()))
+                        .cloned()
+                        .unwrap_or_default(),
+                    match category.hot {
+                        Some(true) => format!("{} (hot)", category.name),
+                        _ => category.name.to_owned(),
+                    },
+                ],
+            })
+            .collect::<Vec<TableItem>>();
+
+        draw_table(
+            f,
+            app,
+            layout_chunk,
+            ("Playlist Categories", &header),
+            &items,
+            picker.selected_index,
+            highlight_state,
+        );
+    }
+}
+
+pub fn draw_playlist_picker<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::PlaylistPicker,
+        current_route.hovered_block == ActiveBlock::PlaylistPicker,
+    );
+
+    let header = [
+        TableHeader {
+            text: "Playlist",
+            width: get_percentage_width(layout_chunk.width, 0.5),
+        },
+        TableHeader {
+            text: "Tracks",
+            width: get_percentage_width(layout_chunk.width, 0.2),
+        },
+        TableHeader {
+            text: "Privacy",
+            width: get_percentage_width(layout_chunk.width, 0.2),
+        },
+    ];
+
+    if let Some(picker) = &app.playlist_picker {
+        let items = picker
+            .playlists
+            .iter()
+            .map(|playlist| TableItem {
+                id: playlist.id.unwrap_or(0).to_string(),
+                format: vec![
+                    playlist.name.to_owned().unwrap_or_default(),
+                    playlist.trackCount.unwrap_or(0).to_string(),
+                    // 10 means private playlist
+                    match playlist.privacy {
+                        Some(10) => "private",
+                        _ => "public",
+                    }
+                    .to_string(),
+                ],
+            })
+            .collect::<Vec<TableItem>>();
+        let title = format!(
+            "Add {} to playlist",
+            picker.track.name.to_owned().unwrap_or_default()
+        );
+
+        draw_table(
+            f,
+            app,
+            layout_chunk,
+            (&title, &header),
+            &items,
+            picker.selected_index,
+            highlight_state,
+        );
+    };
+}
+
+pub fn draw_profiles<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::Profiles,
+        current_route.hovered_block == ActiveBlock::Profiles,
+    );
+
+    let header = [
+        TableHeader {
+            text: "",
+            width: get_percentage_width(layout_chunk.width, 0.05),
+        },
+        TableHeader {
+            text: "Profile",
+            width: get_percentage_width(layout_chunk.width, 0.3),
+        },
+        TableHeader {
+            text: "Account",
+            width: get_percentage_width(layout_chunk.width, 0.4),
+        },
+    ];
+
+    if let Some(profile_list) = &app.profile_list {
+        let items = profile_list
+            .profiles
+            .iter()
+            .map(|profile| TableItem {
+                id: profile.name.to_owned(),
+                format: vec![
+                    // mark current profile
+                    if profile.name == app.profile { "*" } else { "" }.to_string(),
+                    profile.name.to_owned(),
+                    profile.username.to_owned().unwrap_or_default(),
+                ],
+            })
+            .collect::<Vec<TableItem>>();
+
+        draw_table(
+            f,
+            app,
+            layout_chunk,
+            ("Profiles", &header),
+            &items,
+            profile_list.selected_index,
+            highlight_state,
+        );
+    };
+}
+
+pub fn draw_djradio_list<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::DjRadio,
+        current_route.hovered_block == ActiveBlock::DjRadio,
+    );
+
+    let header = [
+        TableHeader {
+            text: "",
+            width: get_percentage_width(layout_chunk.width, 0.05),
+        },
+        TableHeader {
+            text: "DjRadio Name",
+            width: get_percentage_width(layout_chunk.width, 0.3),
+        },
+    ];
+
+    let mut num = match app.djradio_list.to_owned() {
+        Some(djradio_list) => djradio_list.selected_page * (app.block_height - 4),
         None => 0,
     };
 
@@ -1331,6 +2553,160 @@ where
     };
 }
 
+pub fn draw_login<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
+where
+    B: Backend,
+{
+    let current_route = app.get_current_route();
+    let highlight_state = (
+        current_route.active_block == ActiveBlock::Login,
+        current_route.hovered_block == ActiveBlock::Login,
+    );
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)].as_ref())
+        .split(layout_chunk);
+
+    let form = &app.login_form;
+    let mode_index = match form.mode {
+        LoginMode::QrCode => 0,
+        LoginMode::Password => 1,
+        LoginMode::Sms => 2,
+    };
+    Tabs::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Login")
+                .title_style(get_color(highlight_state))
+                .border_style(get_color(highlight_state)),
+        )
+        .titles(&["QR Code", "Password", "SMS"])
+        .select(mode_index)
+        .style(Style::default().fg(Color::Cyan))
+        .highlight_style(Style::default().fg(Color::Yellow))
+        .render(f, chunks[0]);
+
+    match form.mode {
+        LoginMode::QrCode => draw_login_qrcode(f, app, chunks[1], highlight_state),
+        LoginMode::Password | LoginMode::Sms => {
+            draw_login_form(f, app, chunks[1], highlight_state)
+        }
+    }
+}
+
+fn draw_login_qrcode<B>(
+    f: &mut Frame<B>,
+    app: &App,
+    layout_chunk: Rect,
+    highlight_state: (bool, bool),
+) where
+    B: Backend,
+{
+    let qrcode_style = Style::default().fg(Color::White).bg(Color::Black);
+    let text = match &app.qr_login {
+        Some(qr_login) => {
+            let mut text = create_qrcode_lines(&qr_login_url(&qr_login.unikey))
+                .into_iter()
+                .map(|line| Text::styled(format!("{}\n", line), qrcode_style))
+                .collect::<Vec<Text>>();
+            text.push(Text::styled(
+                format!("\n{}", qr_login.status),
+                Style::default().fg(Color::Cyan),
+            ));
+            text
+        }
+        None => vec![Text::raw("Press r to get login qrcode")],
+    };
+
+    Paragraph::new(text.iter())
+        .alignment(Alignment::Center)
+        .style(Style::default().fg(Color::White))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title_style(get_color(highlight_state))
+                .border_style(get_color(highlight_state)),
+        )
+        .render(f, layout_chunk);
+}
+
+fn draw_login_form<B>(
+    f: &mut Frame<B>,
+    app: &App,
+    layout_chunk: Rect,
+    highlight_state: (bool, bool),
+) where
+    B: Backend,
+{
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Length(2),
+                Constraint::Min(0),
+            ]
+            .as_ref(),
+        )
+        .margin(1)
+        .split(layout_chunk);
+
+    Block::default()
+        .borders(Borders::ALL)
+        .title_style(get_color(highlight_state))
+        .border_style(get_color(highlight_state))
+        .render(f, layout_chunk);
+
+    let form = &app.login_form;
+    let (fields, help) = match form.mode {
+        LoginMode::Sms => (
+            [
+                ("Phone", form.account.to_owned()),
+                ("Verification Code", form.captcha.to_owned()),
+            ],
+            "<Ctrl+s> send code | <Enter> login | <Tab> next field | <Left/Right> switch mode",
+        ),
+        _ => (
+            [
+                ("Phone / Email", form.account.to_owned()),
+                // hide password
+                ("Password", "*".repeat(form.password.chars().count())),
+            ],
+            "<Enter> login | <Tab> next field | <Left/Right> switch mode",
+        ),
+    };
+
+    for (i, (title, value)) in fields.iter().enumerate() {
+        let field_state = (highlight_state.0 && i == form.selected_field, false);
+        Paragraph::new([Text::raw(value)].iter())
+            .style(Style::default().fg(Color::Yellow))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(title)
+                    .title_style(get_color(field_state))
+                    .border_style(get_color(field_state)),
+            )
+            .render(f, chunks[i]);
+    }
+
+    let msg = match (&form.error, &form.info) {
+        (Some(error), _) => Text::styled(error, Style::default().fg(Color::LightRed)),
+        (None, Some(info)) => Text::styled(info, Style::default().fg(Color::Cyan)),
+        (None, None) => Text::raw(""),
+    };
+    Paragraph::new([msg].iter())
+        .wrap(true)
+        .render(f, chunks[2]);
+
+    Paragraph::new([Text::styled(help, Style::default().fg(Color::Gray))].iter())
+        .wrap(true)
+        .render(f, chunks[3]);
+}
+
 pub fn draw_error_screen<B>(f: &mut Frame<B>, app: &App, layout_chunk: Rect)
 where
     B: Backend,
@@ -2,14 +2,12 @@ extern crate chrono;
 use super::super::model::artist::Artist;
 use chrono::prelude::DateTime;
 use chrono::Utc;
+use qrcode::render::unicode::Dense1x2;
+use qrcode::QrCode;
 use std::time::{Duration, UNIX_EPOCH};
 use tui::style::{Color, Style};
 use regex::Regex;
-use config::Config;
-
-const FILE_NAME: &str = "Settings.toml";
-const CONFIG_DIR: &str = ".config";
-const APP_CONFIG_DIR: &str = "netease-music-tui";
+use super::super::util::settings::SETTINGS;
 
 lazy_static! {
     /**
@@ -19,12 +17,6 @@ lazy_static! {
      * - group 3: Blue
      */
     static ref COLOR_HEX_REGEX: Regex = Regex::new(r"#(:?[0-9a-fA-F]{2})(:?[0-9a-fA-F]{2})(:?[0-9a-fA-F]{2})").unwrap();
-
-    static ref SETTINGS: Config = Config::builder()
-        .add_source(config::File::with_name(
-                &format!("{}/{}/{}/{}", &dirs::home_dir().unwrap().to_string_lossy(), CONFIG_DIR, APP_CONFIG_DIR, FILE_NAME)))
-        .build()
-        .unwrap();
 }
 
 pub fn parse_hex_color(color: &str) -> Option<Color> {
@@ -121,6 +113,42 @@ pub fn create_datetime_string(timestamp: u64) -> String {
     datetime.format("%Y-%m-%d").to_string()
 }
 
+// rank change in toplist, rank and last rank start from 0
+pub fn create_rank_change_string(rank: usize, last_rank: Option<i32>) -> String {
+    match last_rank {
+        Some(last_rank) if last_rank as usize > rank => format!("▲{}", last_rank as usize - rank),
+        Some(last_rank) if (last_rank as usize) < rank => format!("▼{}", rank - last_rank as usize),
+        Some(_) => "-".to_string(),
+        None => "new".to_string(),
+    }
+}
+
+// first lyric line matching keyword, without highlight tags
+pub fn create_lyric_snippet(lyrics: &[String]) -> String {
+    lyrics
+        .iter()
+        .find(|line| line.contains("<b>"))
+        .or_else(|| lyrics.iter().find(|line| !line.trim().is_empty()))
+        .map(|line| line.replace("<b>", "").replace("</b>", "").trim().to_owned())
+        .unwrap_or_default()
+}
+
+// render qrcode with unicode half blocks, two modules per line
+// colors are inverted so it can be scanned on a dark terminal
+pub fn create_qrcode_lines(data: &str) -> Vec<String> {
+    match QrCode::new(data.as_bytes()) {
+        Ok(code) => code
+            .render::<Dense1x2>()
+            .dark_color(Dense1x2::Light)
+            .light_color(Dense1x2::Dark)
+            .build()
+            .lines()
+            .map(|line| line.to_string())
+            .collect(),
+        Err(_) => vec![],
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -129,4 +157,33 @@ mod tests {
     fn test_create_datatime_string() {
         assert_eq!(create_datetime_string(1576472814620), "2019-12-16");
     }
+
+    #[test]
+    fn test_create_rank_change_string() {
+        assert_eq!(create_rank_change_string(0, Some(3)), "▲3");
+        assert_eq!(create_rank_change_string(5, Some(2)), "▼3");
+        assert_eq!(create_rank_change_string(1, Some(1)), "-");
+        assert_eq!(create_rank_change_string(1, None), "new");
+    }
+
+    #[test]
+    fn test_create_lyric_snippet() {
+        let lyrics = vec![
+            "作词 : 某人".to_string(),
+            "我们<b>一起</b>唱".to_string(),
+            "<b>一起</b>走".to_string(),
+        ];
+        assert_eq!(create_lyric_snippet(&lyrics), "我们一起唱");
+        assert_eq!(create_lyric_snippet(&lyrics[..1]), "作词 : 某人");
+        assert_eq!(create_lyric_snippet(&[]), "");
+    }
+
+    #[test]
+    fn test_create_qrcode_lines() {
+        let lines = create_qrcode_lines("https://music.163.com/login?codekey=test");
+        // every line is same width and two modules make one line
+        let width = lines[0].chars().count();
+        assert!(lines.iter().all(|l| l.chars().count() == width));
+        assert_eq!(lines.len(), (width + 1) / 2);
+    }
 }
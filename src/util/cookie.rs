@@ -0,0 +1,191 @@
+use super::paths;
+use serde_derive::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// domain for cookie without domain attribute
+const DEFAULT_DOMAIN: &str = "music.163.com";
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StoredCookie {
+    pub name: String,
+    pub value: String,
+    pub domain: String,
+    pub path: String,
+    // unix timestamp in seconds, None means session cookie
+    pub expires: Option<u64>,
+}
+
+impl StoredCookie {
+    pub fn new(name: &str, value: &str) -> StoredCookie {
+        StoredCookie {
+            name: name.to_owned(),
+            value: value.to_owned(),
+            domain: DEFAULT_DOMAIN.to_owned(),
+            path: "/".to_owned(),
+            expires: None,
+        }
+    }
+
+    // build from Set-Cookie of response, Max-Age takes precedence over Expires
+    pub fn from_response(cookie: &reqwest::cookie::Cookie, now: u64) -> StoredCookie {
+        let expires = match (cookie.max_age(), cookie.expires()) {
+            (Some(max_age), _) => Some(now + max_age.as_secs()),
+            (None, Some(expires)) => Some(unix_time(expires)),
+            (None, None) => None,
+        };
+        StoredCookie {
+            name: cookie.name().to_owned(),
+            value: cookie.value().to_owned(),
+            domain: cookie
+                .domain()
+                .map(|domain| domain.trim_start_matches('.').to_lowercase())
+                .unwrap_or_else(|| DEFAULT_DOMAIN.to_owned()),
+            path: cookie.path().unwrap_or("/").to_owned(),
+            expires,
+        }
+    }
+
+    fn is_expired(&self, now: u64) -> bool {
+        match self.expires {
+            Some(expires) => expires <= now,
+            None => false,
+        }
+    }
+
+    fn same_key(&self, other: &StoredCookie) -> bool {
+        self.name == other.name && self.domain == other.domain && self.path == other.path
+    }
+
+    // host is the domain or a sub domain of it
+    fn match_host(&self, host: &str) -> bool {
+        host == self.domain || host.ends_with(&format!(".{}", self.domain))
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct CookieJar {
+    cookies: Vec<StoredCookie>,
+    // not saved when no data dir
+    #[serde(skip)]
+    path: Option<PathBuf>,
+}
+
+impl CookieJar {
+    // load saved cookies, empty jar if file not exists
+    pub fn load(path: PathBuf) -> CookieJar {
+        let data = fs::read_to_string(&path).unwrap_or_default();
+        let mut jar = match serde_json::from_str::<CookieJar>(&data) {
+            Ok(jar) => jar,
+            Err(_) => CookieJar::parse_legacy(&data),
+        };
+        jar.path = Some(path);
+        jar
+    }
+
+    // old versions save cookies as `name=value; name=value`
+    fn parse_legacy(data: &str) -> CookieJar {
+        let cookies = data
+            .split(';')
+            .filter_map(|pair| {
+                let mut pair = pair.trim().splitn(2, '=');
+                match (pair.next(), pair.next()) {
+                    (Some(name), Some(value)) if !name.is_empty() => {
+                        Some(StoredCookie::new(name, value))
+                    }
+                    _ => None,
+                }
+            })
+            .collect();
+        CookieJar {
+            cookies,
+            path: None,
+        }
+    }
+
+    // replace cookie with same name, domain and path
+    // expired cookie means delete it
+    pub fn insert(&mut self, cookie: StoredCookie, now: u64) {
+        self.cookies.retain(|c| !c.same_key(&cookie));
+        if !cookie.is_expired(now) {
+            self.cookies.push(cookie);
+        }
+    }
+
+    // cookie header for host, extra cookies are sent if not in jar
+    pub fn header(&self, host: &str, extra: &[(&str, String)], now: u64) -> String {
+        let cookies = self
+            .cookies
+            .iter()
+            .filter(|c| !c.is_expired(now) && c.match_host(host))
+            .collect::<Vec<&StoredCookie>>();
+        let mut pairs = cookies
+            .iter()
+            .map(|c| format!("{}={}", c.name, c.value))
+            .collect::<Vec<String>>();
+        for (name, value) in extra {
+            if !cookies.iter().any(|c| c.name == *name) {
+                pairs.push(format!("{}={}", name, value));
+            }
+        }
+        pairs.join("; ")
+    }
+
+    // write to temp file then rename, so never leave a broken file
+    pub fn save(&self) -> io::Result<()> {
+        let path = match &self.path {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+        let data = serde_json::to_string(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let tmp_path = path.with_extension("tmp");
+        paths::write_secret(&tmp_path, &data)?;
+        fs::rename(&tmp_path, path)
+    }
+}
+
+fn unix_time(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+pub fn now() -> u64 {
+    unix_time(SystemTime::now())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cookie_jar_merge() {
+        let mut jar = CookieJar::parse_legacy("MUSIC_U=abc; __csrf=123");
+        assert_eq!(jar.header("music.163.com", &[], 100), "MUSIC_U=abc; __csrf=123");
+
+        // unrelated cookie keep login cookie
+        jar.insert(StoredCookie::new("JSESSIONID-WYYY", "s"), 100);
+        jar.insert(StoredCookie::new("__csrf", "456"), 100);
+        assert_eq!(
+            jar.header("music.163.com", &[], 100),
+            "MUSIC_U=abc; JSESSIONID-WYYY=s; __csrf=456"
+        );
+
+        // expired cookie delete the old one
+        let mut expired = StoredCookie::new("__csrf", "");
+        expired.expires = Some(50);
+        jar.insert(expired, 100);
+        let mut later = StoredCookie::new("NMTID", "n");
+        later.expires = Some(200);
+        jar.insert(later, 100);
+        assert_eq!(
+            jar.header("music.163.com", &[("os", "pc".to_string())], 100),
+            "MUSIC_U=abc; JSESSIONID-WYYY=s; NMTID=n; os=pc"
+        );
+        assert_eq!(jar.header("music.163.com", &[], 300), "MUSIC_U=abc; JSESSIONID-WYYY=s");
+        assert_eq!(jar.header("example.com", &[], 100), "");
+    }
+}
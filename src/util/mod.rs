@@ -1,5 +1,11 @@
 extern crate num_bigint;
+pub mod cookie;
 pub mod event;
+pub mod paths;
+pub mod query;
+pub mod recent;
+pub mod search_history;
+pub mod settings;
 use num_bigint::BigUint;
 use rand::distributions::Alphanumeric;
 use rand::{thread_rng, Rng};
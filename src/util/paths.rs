@@ -0,0 +1,194 @@
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::os::unix::fs::{MetadataExt, OpenOptionsExt, PermissionsExt};
+use std::path::{Path, PathBuf};
+
+const APP_DIR: &str = "netease-music-tui";
+const CONFIG_FILE_NAME: &str = "Settings.toml";
+const COOKIE_FILE_NAME: &str = "cookie";
+const LOG_FILE_NAME: &str = "ncmt.log";
+const RECENT_FILE_NAME: &str = "recent.json";
+const SEARCH_HISTORY_FILE_NAME: &str = "search_history.json";
+const PROFILES_DIR: &str = "profiles";
+
+// profile using top level account in config
+pub const DEFAULT_PROFILE: &str = "default";
+
+// locations used by old versions, migrated on first run
+const LEGACY_CONFIG_DIR: &str = ".config";
+const LEGACY_COOKIE_PATH: &str = "/tmp/ncmt_cookie";
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Dir {
+    Config,
+    Cache,
+    State,
+    Data,
+}
+
+impl Dir {
+    fn env_var(self) -> &'static str {
+        match self {
+            Dir::Config => "XDG_CONFIG_HOME",
+            Dir::Cache => "XDG_CACHE_HOME",
+            Dir::State => "XDG_STATE_HOME",
+            Dir::Data => "XDG_DATA_HOME",
+        }
+    }
+
+    // default base dir under $HOME when xdg env var not set
+    fn home_default(self) -> &'static str {
+        match self {
+            Dir::Config => ".config",
+            Dir::Cache => ".cache",
+            Dir::State => ".local/state",
+            Dir::Data => ".local/share",
+        }
+    }
+}
+
+// xdg env var must be an absolute path, otherwise it is ignored
+fn resolve(dir: Dir, xdg: Option<String>, home: Option<PathBuf>) -> Option<PathBuf> {
+    let base = match xdg {
+        Some(path) if Path::new(&path).is_absolute() => PathBuf::from(path),
+        _ => home?.join(dir.home_default()),
+    };
+    Some(base.join(APP_DIR))
+}
+
+// app dir for config, cache, state or data
+pub fn app_dir(dir: Dir) -> Option<PathBuf> {
+    resolve(dir, env::var(dir.env_var()).ok(), dirs::home_dir())
+}
+
+// get app dir and create it if not exists
+pub fn ensure_dir(dir: Dir) -> io::Result<PathBuf> {
+    let path = app_dir(dir)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "No $HOME directory found"))?;
+    fs::create_dir_all(&path)?;
+    Ok(path)
+}
+
+pub fn config_file() -> Option<PathBuf> {
+    app_dir(Dir::Config).map(|dir| dir.join(CONFIG_FILE_NAME))
+}
+
+// every profile has own dir, default profile use app dir itself
+fn profile_path(base: PathBuf, profile: &str) -> PathBuf {
+    if profile == DEFAULT_PROFILE {
+        base
+    } else {
+        base.join(PROFILES_DIR).join(profile)
+    }
+}
+
+pub fn profile_dir(dir: Dir, profile: &str) -> Option<PathBuf> {
+    app_dir(dir).map(|base| profile_path(base, profile))
+}
+
+pub fn cookie_file(profile: &str) -> Option<PathBuf> {
+    profile_dir(Dir::Data, profile).map(|dir| dir.join(COOKIE_FILE_NAME))
+}
+
+pub fn recent_file(profile: &str) -> Option<PathBuf> {
+    profile_dir(Dir::Data, profile).map(|dir| dir.join(RECENT_FILE_NAME))
+}
+
+pub fn search_history_file(profile: &str) -> Option<PathBuf> {
+    profile_dir(Dir::Data, profile).map(|dir| dir.join(SEARCH_HISTORY_FILE_NAME))
+}
+
+pub fn log_file() -> Option<PathBuf> {
+    app_dir(Dir::State).map(|dir| dir.join(LOG_FILE_NAME))
+}
+
+// write file only readable by current user, such as cookie
+pub fn write_secret(path: &Path, data: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .mode(0o600)
+        .open(path)?;
+    // mode only works when create, fix permissions of old file
+    file.set_permissions(fs::Permissions::from_mode(0o600))?;
+    file.write_all(data.as_bytes())
+}
+
+// move files from old locations to xdg dirs
+// only when the new file not exists yet
+pub fn migrate() -> io::Result<()> {
+    let home = match dirs::home_dir() {
+        Some(home) => home,
+        None => return Ok(()),
+    };
+
+    if let Some(config_file) = config_file() {
+        let legacy = home
+            .join(LEGACY_CONFIG_DIR)
+            .join(APP_DIR)
+            .join(CONFIG_FILE_NAME);
+        if legacy != config_file && legacy.is_file() && !config_file.exists() {
+            ensure_dir(Dir::Config)?;
+            fs::copy(&legacy, &config_file)?;
+        }
+    }
+
+    if let Some(cookie_file) = cookie_file(DEFAULT_PROFILE) {
+        let legacy = Path::new(LEGACY_COOKIE_PATH);
+        // /tmp is shared, only trust cookie file created by ourself
+        let owned = match (fs::metadata(legacy), fs::metadata(&home)) {
+            (Ok(file), Ok(home)) => file.is_file() && file.uid() == home.uid(),
+            _ => false,
+        };
+        if owned {
+            if !cookie_file.exists() {
+                write_secret(&cookie_file, &fs::read_to_string(legacy)?)?;
+            }
+            fs::remove_file(legacy)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        let home = Some(PathBuf::from("/home/ncmt"));
+        assert_eq!(
+            resolve(Dir::Config, None, home.to_owned()),
+            Some(PathBuf::from("/home/ncmt/.config/netease-music-tui"))
+        );
+        assert_eq!(
+            resolve(Dir::State, None, home.to_owned()),
+            Some(PathBuf::from("/home/ncmt/.local/state/netease-music-tui"))
+        );
+        assert_eq!(
+            resolve(Dir::Cache, Some("/var/cache".to_string()), home.to_owned()),
+            Some(PathBuf::from("/var/cache/netease-music-tui"))
+        );
+        // relative path is ignored
+        assert_eq!(
+            resolve(Dir::Data, Some("data".to_string()), home),
+            Some(PathBuf::from("/home/ncmt/.local/share/netease-music-tui"))
+        );
+        assert_eq!(resolve(Dir::Data, None, None), None);
+    }
+
+    #[test]
+    fn test_profile_path() {
+        let base = PathBuf::from("/data/netease-music-tui");
+        assert_eq!(profile_path(base.to_owned(), DEFAULT_PROFILE), base);
+        assert_eq!(
+            profile_path(base, "work"),
+            PathBuf::from("/data/netease-music-tui/profiles/work")
+        );
+    }
+}
@@ -0,0 +1,239 @@
+use super::super::model::album::Album;
+use super::super::model::artist::Artist;
+use super::super::model::playlist::Track;
+
+// search type of `type:` qualifier, in order of search result tabs
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SearchKind {
+    Song,
+    Artist,
+    Album,
+    Playlist,
+    DjRadio,
+    Lyric,
+    User,
+}
+
+impl SearchKind {
+    fn parse(value: &str) -> Option<SearchKind> {
+        match value {
+            "song" | "songs" | "track" => Some(SearchKind::Song),
+            "artist" => Some(SearchKind::Artist),
+            "album" => Some(SearchKind::Album),
+            "playlist" => Some(SearchKind::Playlist),
+            "djradio" | "radio" => Some(SearchKind::DjRadio),
+            "lyric" | "lyrics" => Some(SearchKind::Lyric),
+            "user" => Some(SearchKind::User),
+            _ => None,
+        }
+    }
+
+    pub fn tab(self) -> usize {
+        self as usize
+    }
+}
+
+// search input like `artist:周杰伦 album:叶惠美 type:album`
+// quote value with spaces or colon, such as `artist:"Taylor Swift"`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SearchQuery {
+    pub keyword: String,
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub kind: Option<SearchKind>,
+}
+
+impl SearchQuery {
+    pub fn parse(input: &str) -> Result<SearchQuery, String> {
+        let mut query = SearchQuery::default();
+        let mut words = vec![];
+        for (token, colon_at) in tokenize(input)? {
+            let (key, value) = match colon_at {
+                Some(index) => (token[..index].to_lowercase(), token[index + 1..].trim()),
+                None => {
+                    words.push(token);
+                    continue;
+                }
+            };
+            match key.as_str() {
+                "artist" | "album" | "type" if value.is_empty() => {
+                    return Err(format!("empty value of `{}`", key));
+                }
+                "artist" => query.artist = Some(value.to_owned()),
+                "album" => query.album = Some(value.to_owned()),
+                "type" => match SearchKind::parse(&value.to_lowercase()) {
+                    Some(kind) => query.kind = Some(kind),
+                    None => return Err(format!("unknown type `{}`", value)),
+                },
+                // not a qualifier, such as `Re:Zero` or `1:00`
+                _ => words.push(token),
+            }
+        }
+        query.keyword = words.join(" ");
+        if query.search_keyword().is_empty() {
+            return Err("nothing to search".to_string());
+        }
+        Ok(query)
+    }
+
+    // keyword sent to api, qualifier values help the fuzzy search
+    pub fn search_keyword(&self) -> String {
+        let words = [Some(&self.keyword), self.artist.as_ref(), self.album.as_ref()];
+        words
+            .iter()
+            .flatten()
+            .filter(|word| !word.is_empty())
+            .map(|word| word.as_str())
+            .collect::<Vec<&str>>()
+            .join(" ")
+    }
+
+    pub fn is_filtered(&self) -> bool {
+        self.artist.is_some() || self.album.is_some()
+    }
+
+    // tab to show, songs if only filtered by artist or album
+    pub fn tab(&self) -> Option<usize> {
+        match self.kind {
+            Some(kind) => Some(kind.tab()),
+            None if self.is_filtered() => Some(SearchKind::Song.tab()),
+            None => None,
+        }
+    }
+
+    pub fn matches_track(&self, track: &Track) -> bool {
+        let artists = track.artists.as_ref().map(|artists| {
+            artists
+                .iter()
+                .map(|artist| artist.name.as_str())
+                .collect::<Vec<&str>>()
+        });
+        let album = track.album.as_ref().and_then(|album| album.name.as_deref());
+        self.matches(artists.unwrap_or_default(), album)
+    }
+
+    pub fn matches_album(&self, album: &Album) -> bool {
+        let artists = album.artist.iter().map(|artist| artist.name.as_str()).collect();
+        self.matches(artists, album.name.as_deref())
+    }
+
+    // artist has no album, album qualifier is ignored
+    pub fn matches_artist(&self, artist: &Artist) -> bool {
+        match &self.artist {
+            Some(name) => contains(&artist.name, name),
+            None => true,
+        }
+    }
+
+    fn matches(&self, artists: Vec<&str>, album: Option<&str>) -> bool {
+        let artist_matched = match &self.artist {
+            Some(name) => artists.iter().any(|artist| contains(artist, name)),
+            None => true,
+        };
+        let album_matched = match (&self.album, album) {
+            (Some(name), Some(album)) => contains(album, name),
+            (Some(_), None) => false,
+            (None, _) => true,
+        };
+        artist_matched && album_matched
+    }
+}
+
+fn contains(text: &str, pattern: &str) -> bool {
+    text.to_lowercase().contains(&pattern.to_lowercase())
+}
+
+// split input by whitespace outside quotes
+// with byte index of first colon outside quotes in each token
+fn tokenize(input: &str) -> Result<Vec<(String, Option<usize>)>, String> {
+    let mut tokens = vec![];
+    let mut token = String::new();
+    let mut colon_at = None;
+    let mut quoted = false;
+    for c in input.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            ':' if !quoted && colon_at.is_none() => {
+                colon_at = Some(token.len());
+                token.push(c);
+            }
+            c if c.is_whitespace() && !quoted => {
+                if !token.is_empty() {
+                    tokens.push((token, colon_at));
+                }
+                token = String::new();
+                colon_at = None;
+            }
+            c => token.push(c),
+        }
+    }
+    if quoted {
+        return Err("unclosed quote".to_string());
+    }
+    if !token.is_empty() {
+        tokens.push((token, colon_at));
+    }
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_query() {
+        let query = SearchQuery::parse("晴天 artist:周杰伦 album:叶惠美 type:Album").unwrap();
+        assert_eq!(query.keyword, "晴天");
+        assert_eq!(query.artist, Some("周杰伦".to_string()));
+        assert_eq!(query.album, Some("叶惠美".to_string()));
+        assert_eq!(query.kind, Some(SearchKind::Album));
+        assert_eq!(query.tab(), Some(2));
+        assert_eq!(query.search_keyword(), "晴天 周杰伦 叶惠美");
+
+        let query = SearchQuery::parse("artist:\"Taylor Swift\" \"Re:Zero\"").unwrap();
+        assert_eq!(query.keyword, "Re:Zero");
+        assert_eq!(query.artist, Some("Taylor Swift".to_string()));
+        assert_eq!(query.tab(), Some(0));
+
+        let query = SearchQuery::parse("  hello   world ").unwrap();
+        assert_eq!(query.keyword, "hello world");
+        assert_eq!(query.tab(), None);
+
+        let query = SearchQuery::parse("Re:Zero 1:00 feat:xxx").unwrap();
+        assert_eq!(query.keyword, "Re:Zero 1:00 feat:xxx");
+        assert_eq!(query.tab(), None);
+    }
+
+    #[test]
+    fn test_parse_query_error() {
+        assert_eq!(SearchQuery::parse("type:movie x"), Err("unknown type `movie`".to_string()));
+        assert_eq!(SearchQuery::parse("artist: x"), Err("empty value of `artist`".to_string()));
+        assert_eq!(SearchQuery::parse("\"abc"), Err("unclosed quote".to_string()));
+        assert_eq!(SearchQuery::parse("type:song"), Err("nothing to search".to_string()));
+    }
+
+    #[test]
+    fn test_query_matches() {
+        let query = SearchQuery::parse("artist:jay album:fantasy").unwrap();
+        let artist = Artist {
+            name: "Jay Chou".to_string(),
+            ..Default::default()
+        };
+        let album = Album {
+            id: None,
+            name: Some("Fantasy".to_string()),
+            size: None,
+            artist: Some(artist.to_owned()),
+            publishTime: None,
+            company: None,
+        };
+        assert!(query.matches_album(&album));
+        assert!(query.matches_artist(&artist));
+
+        let other = Album {
+            name: Some("Jay".to_string()),
+            ..album
+        };
+        assert!(!query.matches_album(&other));
+    }
+}
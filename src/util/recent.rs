@@ -0,0 +1,80 @@
+use super::super::model::playlist::Track;
+use super::paths;
+use serde_derive::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+// keep latest tracks only
+const MAX_RECENT_TRACKS: usize = 200;
+
+// recently played tracks, newest first
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct RecentTracks {
+    pub tracks: Vec<Track>,
+    // not saved when no data dir
+    #[serde(skip)]
+    path: Option<PathBuf>,
+}
+
+impl RecentTracks {
+    // load saved tracks, empty list if file not exists or broken
+    pub fn load(path: PathBuf) -> RecentTracks {
+        let data = fs::read_to_string(&path).unwrap_or_default();
+        let mut recent = serde_json::from_str::<RecentTracks>(&data).unwrap_or_default();
+        recent.path = Some(path);
+        recent
+    }
+
+    // move track to the front if played before
+    pub fn push(&mut self, track: Track) {
+        self.tracks.retain(|t| *t != track);
+        self.tracks.insert(0, track);
+        self.tracks.truncate(MAX_RECENT_TRACKS);
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let path = match &self.path {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+        let data = serde_json::to_string(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let tmp_path = path.with_extension("tmp");
+        paths::write_secret(&tmp_path, &data)?;
+        fs::rename(&tmp_path, path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn track(id: i64) -> Track {
+        Track {
+            name: Some(id.to_string()),
+            fee: None,
+            id: Some(id),
+            artists: None,
+            album: None,
+            reason: None,
+            last_rank: None,
+        }
+    }
+
+    #[test]
+    fn test_recent_push() {
+        let mut recent = RecentTracks::default();
+        recent.push(track(1));
+        recent.push(track(2));
+        recent.push(track(1));
+        let ids = recent.tracks.iter().map(|t| t.id).collect::<Vec<Option<i64>>>();
+        assert_eq!(ids, vec![Some(1), Some(2)]);
+
+        for id in 0..300 {
+            recent.push(track(id));
+        }
+        assert_eq!(recent.tracks.len(), MAX_RECENT_TRACKS);
+        assert_eq!(recent.tracks[0].id, Some(299));
+    }
+}
@@ -0,0 +1,71 @@
+use super::paths;
+use serde_derive::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+// keep latest queries only
+const MAX_QUERIES: usize = 20;
+
+// recent search queries, newest first
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SearchHistory {
+    pub queries: Vec<String>,
+    // not saved when no data dir
+    #[serde(skip)]
+    path: Option<PathBuf>,
+}
+
+impl SearchHistory {
+    // load saved queries, empty list if file not exists or broken
+    pub fn load(path: PathBuf) -> SearchHistory {
+        let data = fs::read_to_string(&path).unwrap_or_default();
+        let mut history = serde_json::from_str::<SearchHistory>(&data).unwrap_or_default();
+        history.path = Some(path);
+        history
+    }
+
+    // move query to the front if searched before
+    pub fn push(&mut self, query: &str) {
+        let query = query.trim();
+        if query.is_empty() {
+            return;
+        }
+        self.queries.retain(|q| q != query);
+        self.queries.insert(0, query.to_owned());
+        self.queries.truncate(MAX_QUERIES);
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let path = match &self.path {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+        let data = serde_json::to_string(self)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let tmp_path = path.with_extension("tmp");
+        paths::write_secret(&tmp_path, &data)?;
+        fs::rename(&tmp_path, path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_history_push() {
+        let mut history = SearchHistory::default();
+        history.push("a");
+        history.push(" b ");
+        history.push("");
+        history.push("a");
+        assert_eq!(history.queries, vec!["a", "b"]);
+
+        for i in 0..30 {
+            history.push(&i.to_string());
+        }
+        assert_eq!(history.queries.len(), MAX_QUERIES);
+        assert_eq!(history.queries[0], "29");
+    }
+}
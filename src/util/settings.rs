@@ -0,0 +1,85 @@
+use super::paths::{config_file, DEFAULT_PROFILE};
+use config::Config;
+use std::env;
+
+
+// standard proxy env vars, checked in order
+const PROXY_ENV_VARS: [&str; 4] = ["ALL_PROXY", "all_proxy", "HTTPS_PROXY", "https_proxy"];
+
+lazy_static! {
+    pub static ref SETTINGS: Config = {
+        let mut builder = Config::builder();
+        if let Some(path) = config_file() {
+            builder = builder.add_source(config::File::from(path).required(false));
+        }
+        builder.build().unwrap()
+    };
+}
+
+// proxy for api requests
+// `proxy` in config first, then ALL_PROXY / HTTPS_PROXY env
+pub fn api_proxy() -> Option<String> {
+    match SETTINGS.get_string("proxy") {
+        Ok(proxy) if !proxy.is_empty() => Some(proxy),
+        _ => PROXY_ENV_VARS
+            .iter()
+            .filter_map(|key| env::var(key).ok())
+            .find(|proxy| !proxy.is_empty()),
+    }
+}
+
+// proxy for audio downloads
+// use `stream_proxy` if set, otherwise same as api proxy
+pub fn stream_proxy() -> Option<String> {
+    match SETTINGS.get_string("stream_proxy") {
+        Ok(proxy) if !proxy.is_empty() => Some(proxy),
+        _ => api_proxy(),
+    }
+}
+
+// account profile, each has own cookie and cache
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProfileConfig {
+    pub name: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+// profile name is used as dir name
+fn valid_profile_name(name: &str) -> bool {
+    !name.is_empty() && !name.starts_with('.') && !name.contains('/') && name != DEFAULT_PROFILE
+}
+
+// default profile use top level account, others in [profiles.NAME]
+pub fn profiles() -> Vec<ProfileConfig> {
+    let mut profiles = vec![ProfileConfig {
+        name: DEFAULT_PROFILE.to_string(),
+        username: SETTINGS.get_string("username").ok(),
+        password: SETTINGS.get_string("password").ok(),
+    }];
+    if let Ok(table) = SETTINGS.get_table("profiles") {
+        let mut names: Vec<String> = table
+            .into_keys()
+            .filter(|name| valid_profile_name(name))
+            .collect();
+        names.sort();
+        for name in names {
+            profiles.push(ProfileConfig {
+                username: SETTINGS.get_string(&format!("profiles.{}.username", name)).ok(),
+                password: SETTINGS.get_string(&format!("profiles.{}.password", name)).ok(),
+                name,
+            });
+        }
+    }
+    profiles
+}
+
+// profile used when start, set by `profile` in config
+pub fn default_profile() -> ProfileConfig {
+    let mut profiles = profiles();
+    let index = match SETTINGS.get_string("profile") {
+        Ok(name) => profiles.iter().position(|p| p.name == name).unwrap_or(0),
+        Err(_) => 0,
+    };
+    profiles.swap_remove(index)
+}